@@ -0,0 +1,324 @@
+use proc_macro2::Span;
+use syn::{spanned::Spanned, AttributeArgs, Error, Ident, Lit, LitInt, LitStr, Meta, NestedMeta};
+
+const KEYS: &[&str] = &[
+    "year",
+    "day",
+    "part1",
+    "part2",
+    "generator",
+    "sample",
+    "sample_part1",
+    "sample_part2",
+    "frames",
+];
+
+/// Validated arguments of `#[aoc_main(...)]`.
+pub struct AocArgs {
+    pub year: LitInt,
+    pub day: LitInt,
+    pub part1: Option<Ident>,
+    pub part2: Option<Ident>,
+    pub generator: Option<Ident>,
+    /// Sample input, relative to the `input` directory of the crate.
+    pub sample: Option<LitStr>,
+    /// Expected answers on the sample, as rendered by the runner.
+    pub sample_part1: Option<LitStr>,
+    pub sample_part2: Option<LitStr>,
+    /// Frames of the solving process, printed with `--frames` or `--render`.
+    pub frames: Option<Ident>,
+}
+
+impl AocArgs {
+    pub fn parse(attributes: &AttributeArgs) -> syn::Result<AocArgs> {
+        let mut errors = Errors::default();
+        let mut year = None;
+        let mut day = None;
+        let mut part1 = None;
+        let mut part2 = None;
+        let mut generator = None;
+        let mut sample = None;
+        let mut sample_part1 = None;
+        let mut sample_part2 = None;
+        let mut frames = None;
+        let mut keys = Vec::new();
+
+        for attr in attributes {
+            let named = match attr {
+                NestedMeta::Meta(Meta::NameValue(named)) => named,
+                _ => {
+                    errors.push(Error::new(attr.span(), "expected `key = value`"));
+                    continue;
+                }
+            };
+
+            let key = named
+                .path
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            keys.push(key.clone());
+
+            let result = match key.as_str() {
+                "year" => parse_int(&named.lit, 2015..=9999).map(|lit| set(&mut year, lit)),
+                "day" => parse_int(&named.lit, 1..=25).map(|lit| set(&mut day, lit)),
+                "part1" => parse_ident(&named.lit).map(|ident| set(&mut part1, ident)),
+                "part2" => parse_ident(&named.lit).map(|ident| set(&mut part2, ident)),
+                "generator" => parse_ident(&named.lit).map(|ident| set(&mut generator, ident)),
+                "sample" => parse_str(&named.lit).map(|path| set(&mut sample, path)),
+                "sample_part1" => {
+                    parse_expected(&named.lit).map(|answer| set(&mut sample_part1, answer))
+                }
+                "sample_part2" => {
+                    parse_expected(&named.lit).map(|answer| set(&mut sample_part2, answer))
+                }
+                "frames" => parse_ident(&named.lit).map(|ident| set(&mut frames, ident)),
+                _ => Err(Error::new(
+                    named.path.span(),
+                    format!("unknown key, expected one of: {}", KEYS.join(", ")),
+                )),
+            };
+
+            match result {
+                Ok(true) => {}
+                Ok(false) => errors.push(Error::new(
+                    named.path.span(),
+                    format!("duplicate key `{}`", key),
+                )),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        for required in ["year", "day"] {
+            if !keys.iter().any(|key| key == required) {
+                errors.push(Error::new(
+                    Span::call_site(),
+                    format!("missing `{} = ...`", required),
+                ));
+            }
+        }
+        for (key, expected, part) in [("1", &sample_part1, &part1), ("2", &sample_part2, &part2)] {
+            if let Some(expected) = expected {
+                if sample.is_none() {
+                    errors.push(Error::new(
+                        expected.span(),
+                        format!("`sample_part{}` requires `sample = ...`", key),
+                    ));
+                }
+                if part.is_none() {
+                    errors.push(Error::new(
+                        expected.span(),
+                        format!("`sample_part{}` requires `part{} = ...`", key, key),
+                    ));
+                }
+            }
+        }
+        if let (Some(sample), None, None) = (&sample, &sample_part1, &sample_part2) {
+            errors.push(Error::new(
+                sample.span(),
+                "`sample` requires `sample_part1 = ...` or `sample_part2 = ...`",
+            ));
+        }
+
+        errors.finish()?;
+
+        Ok(AocArgs {
+            year: year.expect("year is checked"),
+            day: day.expect("day is checked"),
+            part1,
+            part2,
+            generator,
+            sample,
+            sample_part1,
+            sample_part2,
+            frames,
+        })
+    }
+}
+
+/// Errors collected while validating, reported together.
+#[derive(Default)]
+pub struct Errors(Option<Error>);
+
+impl Errors {
+    pub fn push(&mut self, error: Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    pub fn finish(self) -> syn::Result<()> {
+        match self.0 {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+}
+
+fn set<T>(slot: &mut Option<T>, value: T) -> bool {
+    let first = slot.is_none();
+    if first {
+        *slot = Some(value);
+    }
+    first
+}
+
+fn parse_int(lit: &Lit, range: std::ops::RangeInclusive<u32>) -> syn::Result<LitInt> {
+    match lit {
+        Lit::Int(int) => {
+            let value = int.base10_parse::<u32>()?;
+            if range.contains(&value) {
+                Ok(int.clone())
+            } else {
+                Err(Error::new(
+                    int.span(),
+                    format!("expected a value in {}..={}", range.start(), range.end()),
+                ))
+            }
+        }
+        _ => Err(Error::new(lit.span(), "expected an integer literal")),
+    }
+}
+
+fn parse_ident(lit: &Lit) -> syn::Result<Ident> {
+    match lit {
+        Lit::Str(string) => {
+            let mut ident = syn::parse_str::<Ident>(&string.value())
+                .map_err(|_| Error::new(string.span(), "expected the name of a function"))?;
+            ident.set_span(string.span());
+            Ok(ident)
+        }
+        _ => Err(Error::new(lit.span(), "expected a string literal")),
+    }
+}
+
+fn parse_str(lit: &Lit) -> syn::Result<LitStr> {
+    match lit {
+        Lit::Str(string) => Ok(string.clone()),
+        _ => Err(Error::new(lit.span(), "expected a string literal")),
+    }
+}
+
+/// Expected answer, integers are compared by their decimal digits.
+fn parse_expected(lit: &Lit) -> syn::Result<LitStr> {
+    match lit {
+        Lit::Str(string) => Ok(string.clone()),
+        Lit::Int(int) => Ok(LitStr::new(int.base10_digits(), int.span())),
+        _ => Err(Error::new(
+            lit.span(),
+            "expected an integer or string literal",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, AttributeArgs};
+
+    use super::AocArgs;
+
+    fn errors(attributes: AttributeArgs) -> Vec<String> {
+        match AocArgs::parse(&attributes) {
+            Ok(_) => vec![],
+            Err(errors) => errors.into_iter().map(|err| err.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_valid_args() {
+        let attributes: AttributeArgs = vec![
+            parse_quote!(year = 2022),
+            parse_quote!(day = 12),
+            parse_quote!(part1 = "part1"),
+            parse_quote!(generator = "maze"),
+            parse_quote!(sample = "sample12.txt"),
+            parse_quote!(sample_part1 = 31),
+            parse_quote!(frames = "frames"),
+        ];
+
+        let args = AocArgs::parse(&attributes).unwrap();
+
+        assert_eq!(args.day.base10_digits(), "12");
+        assert_eq!(args.part1.unwrap(), "part1");
+        assert_eq!(args.generator.unwrap(), "maze");
+        assert!(args.part2.is_none());
+        assert_eq!(args.sample.unwrap().value(), "sample12.txt");
+        assert_eq!(args.sample_part1.unwrap().value(), "31");
+        assert!(args.sample_part2.is_none());
+        assert_eq!(args.frames.unwrap(), "frames");
+    }
+
+    #[test]
+    fn test_invalid_args() {
+        let attributes: AttributeArgs = vec![
+            parse_quote!(year = "2022"),
+            parse_quote!(day = 26),
+            parse_quote!(part3 = "part3"),
+            parse_quote!(part1 = "not a fn"),
+        ];
+
+        assert_eq!(
+            errors(attributes),
+            vec![
+                "expected an integer literal",
+                "expected a value in 1..=25",
+                "unknown key, expected one of: year, day, part1, part2, generator, sample, \
+                 sample_part1, sample_part2, frames",
+                "expected the name of a function",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_and_missing_args() {
+        let attributes: AttributeArgs = vec![parse_quote!(year = 2022), parse_quote!(year = 2021)];
+
+        assert_eq!(
+            errors(attributes),
+            vec!["duplicate key `year`", "missing `day = ...`"]
+        );
+    }
+
+    #[test]
+    fn test_sample_args() {
+        let attributes: AttributeArgs = vec![
+            parse_quote!(year = 2022),
+            parse_quote!(day = 12),
+            parse_quote!(part1 = "part1"),
+            parse_quote!(sample_part1 = 31),
+            parse_quote!(sample_part2 = 29.0),
+        ];
+
+        assert_eq!(
+            errors(attributes),
+            vec![
+                "expected an integer or string literal",
+                "`sample_part1` requires `sample = ...`",
+            ]
+        );
+
+        let attributes: AttributeArgs = vec![
+            parse_quote!(year = 2022),
+            parse_quote!(day = 12),
+            parse_quote!(sample = "sample12.txt"),
+            parse_quote!(sample_part2 = "29"),
+        ];
+
+        assert_eq!(
+            errors(attributes),
+            vec!["`sample_part2` requires `part2 = ...`"]
+        );
+
+        let attributes: AttributeArgs = vec![
+            parse_quote!(year = 2022),
+            parse_quote!(day = 12),
+            parse_quote!(sample = "sample12.txt"),
+        ];
+
+        assert_eq!(
+            errors(attributes),
+            vec!["`sample` requires `sample_part1 = ...` or `sample_part2 = ...`"]
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use std::{io, path::Path};
+
+/// Days of the `dayN.rs` files in `src`, sorted.
+pub fn find(src: &Path) -> io::Result<Vec<u32>> {
+    let mut days = vec![];
+    for entry in std::fs::read_dir(src)? {
+        let name = entry?.file_name();
+        let day = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("day")?.strip_suffix(".rs")?.parse().ok());
+        if let Some(day) = day {
+            days.push(day);
+        }
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find;
+
+    #[test]
+    fn test_find() {
+        let dir = std::env::temp_dir().join(format!("aoc-days-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("bin")).unwrap();
+        for file in [
+            "day10.rs",
+            "day2.rs",
+            "lib.rs",
+            "dayx.rs",
+            "day3.txt",
+            "bin/day4.rs",
+        ] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+
+        let days = find(&dir);
+        let missing = find(&dir.join("missing"));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(days.unwrap(), vec![2, 10]);
+        assert!(missing.is_err());
+    }
+}
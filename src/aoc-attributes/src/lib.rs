@@ -1,36 +1,50 @@
 use proc_macro::TokenStream;
 
-use quote::quote;
-use syn::{parse_macro_input, AttributeArgs, ItemFn, Meta};
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, AttributeArgs, DeriveInput, ItemFn};
 
-fn find_attribute(name: &str, attributes: &AttributeArgs) -> Option<String> {
-    attributes
-        .iter()
-        .filter_map(|attr| match attr {
-            syn::NestedMeta::Meta(Meta::NameValue(named)) => {
-                if named.path.segments.last().expect("Panic").ident == name {
-                    match &named.lit {
-                        syn::Lit::Str(string) => Some(string.value()),
-                        syn::Lit::Int(int) => Some(int.base10_digits().to_string()),
-                        _ => None,
-                    }
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        })
-        .next()
-}
+mod args;
+mod days;
+mod pattern;
+
+use args::AocArgs;
+use pattern::{AocPattern, Segment};
 
+/// Wires the parts and the input generator of a day into its `main` and into
+/// the `solution()` run by the year runner.
+///
+/// The `day` must match the `dayN` module or binary it is declared in:
+///
+/// ```
+/// mod day15 {
+///     #[aoc_attributes::aoc_main(year = 2022, day = 15)]
+///     pub fn main() {}
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ```compile_fail,E0080
+/// mod day15 {
+///     #[aoc_attributes::aoc_main(year = 2022, day = 14)]
+///     pub fn main() {}
+/// }
+/// # fn main() {}
+/// ```
 #[proc_macro_attribute]
 pub fn aoc_main(attributes: TokenStream, item: TokenStream) -> TokenStream {
     let function = parse_macro_input!(item as ItemFn);
     let args: AttributeArgs = parse_macro_input!(attributes as AttributeArgs);
-    generate_aoc_main(function, args).into()
+    match AocArgs::parse(&args) {
+        Ok(args) => generate_aoc_main(function, args).into(),
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote!(#err #function).into()
+        }
+    }
 }
 
-fn generate_aoc_main(function: ItemFn, attributes: AttributeArgs) -> proc_macro2::TokenStream {
+fn generate_aoc_main(function: ItemFn, args: AocArgs) -> proc_macro2::TokenStream {
     let fn_body = function.block;
     let sig = function.sig;
     let vis = function.vis;
@@ -38,66 +52,229 @@ fn generate_aoc_main(function: ItemFn, attributes: AttributeArgs) -> proc_macro2
     let fn_args = sig.inputs;
     let fn_return_type = sig.output;
 
-    let year = find_attribute("year", &attributes).unwrap_or_default();
-    let day = find_attribute("day", &attributes).unwrap_or_default();
-    let part1 = find_attribute("part1", &attributes)
-        .map(|p1| syn::Ident::new(&p1, proc_macro2::Span::call_site()))
+    let AocArgs {
+        year,
+        day,
+        part1,
+        part2,
+        generator,
+        sample,
+        sample_part1,
+        sample_part2,
+        frames,
+    } = args;
+
+    let check_day = {
+        let value = day.base10_parse::<u32>().expect("day is checked");
+        let message = format!("`day = {}` does not match the module ", value);
+        quote_spanned! {day.span()=>
+            const _: () = match aoc_runner::module_day(module_path!()) {
+                Some(module_day) if module_day != #value => panic!(concat!(#message, module_path!())),
+                _ => {}
+            };
+        }
+    };
+
+    let samples = sample
+        .map(|sample| {
+            let tests = vec![(1u8, sample_part1), (2u8, sample_part2)]
+                .into_iter()
+                .filter_map(|(part, expected)| {
+                    let expected = expected?;
+                    let name = quote::format_ident!("sample_part{}", part);
+                    Some(quote! {
+                        #[test]
+                        fn #name() {
+                            let report = super::solution().run(SAMPLE, Default::default());
+                            assert_eq!(report.answer(#part), Ok(String::from(#expected)));
+                        }
+                    })
+                });
+            quote! {
+                #[cfg(test)]
+                mod aoc_samples {
+                    static SAMPLE: &str =
+                        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/input/", #sample));
+
+                    #(#tests)*
+                }
+            }
+        })
+        .unwrap_or_else(|| quote! {});
+
+    let parse = generator
+        .as_ref()
         .map(|ident| {
             quote! {
-             let now = start("1");
-             let result = #ident();
-             stop_and_log(now,"1",&result);
+             let parsed = match harness.generator(|strict| {
+                 aoc_runner::returned!(aoc_runner::generator::call(&#ident, input, strict))
+             }) {
+                 Some(parsed) => parsed,
+                 None => return,
+             };
+            }
+        })
+        .unwrap_or_else(|| quote! {});
+
+    let call_part = |ident: syn::Ident| match generator {
+        Some(_) => quote! { #ident(&parsed) },
+        None => quote! { aoc_runner::part::call(&#ident, input) },
+    };
+
+    let part1 = part1
+        .map(call_part)
+        .map(|call| {
+            quote! {
+             harness.part(1, || {
+                 aoc_runner::returned!(#call).map(|answer| aoc_runner::rendered!(answer))
+             });
+            }
+        })
+        .unwrap_or_else(|| quote! {});
+
+    let part2 = part2
+        .map(call_part)
+        .map(|call| {
+            quote! {
+             harness.part(2, || {
+                 aoc_runner::returned!(#call).map(|answer| aoc_runner::rendered!(answer))
+             });
             }
         })
         .unwrap_or_else(|| quote! {});
 
-    let part2 = find_attribute("part2", &attributes)
-        .map(|p2| syn::Ident::new(&p2, proc_macro2::Span::call_site()))
+    let frames = frames
         .map(|ident| {
             quote! {
-             let now = start("2");
-             let result = #ident();
-             stop_and_log(now,"2",&result);
+                .with_frames(|input, every| aoc_runner::returned!(#ident(input, every)))
             }
         })
         .unwrap_or_else(|| quote! {});
 
     quote! {
-        #vis fn #fn_name(#fn_args) #fn_return_type {
+        pub fn solution() -> aoc_runner::Solution {
+            aoc_runner::Solution::new(#year, #day, |#[allow(unused_variables)] input, #[allow(unused_variables)] harness| {
+                #parse
 
-            use std::time::Instant;
+                #part1
 
+                #part2
+            })
+            #frames
+        }
 
-            fn start(part :&str) -> Instant {
-                let year = #year;
-                let day = #day;
+        #check_day
 
-                println!("Running AoC {} day {}, part {}",year,day,part);
+        #samples
 
-                Instant::now()
-            }
+        #vis fn #fn_name(#fn_args) #fn_return_type {
 
+            solution().main(env!("CARGO_MANIFEST_DIR"));
 
-            fn stop_and_log(now : std::time::Instant, part:&str, result : impl std::fmt::Debug) {
+            #fn_body
 
-                let duration = now.elapsed();
+        }
 
-                if duration.as_micros() < 1000 {
-                    println!("=> Part {} execution time: {}µs with result : {:?}", part, duration.as_micros(),result);
-                } else if duration.as_millis() < 1000  {
-                    println!("=> Part {} execution time: {}ms with result : {:?}", part , duration.as_millis(),result);
-                }else {
-                    println!("=> Part {} execution time: {}s with result : {:?}", part, duration.as_secs(),result);
-                }
-            }
+    }
+}
 
-            #part1
+/// Solutions of the `src/dayN.rs` modules of the crate, sorted by day:
+///
+/// ```ignore
+/// pub mod day1;
+/// pub mod day2;
+///
+/// pub fn solutions() -> Vec<aoc_runner::Solution> {
+///     aoc_attributes::solutions!()
+/// }
+/// ```
+///
+/// Every day file is registered, the crate fails to compile when one of them
+/// is not declared as a module.
+#[proc_macro]
+pub fn solutions(input: TokenStream) -> TokenStream {
+    if !input.is_empty() {
+        return syn::Error::new(Span::call_site(), "`solutions!` takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let src = std::env::var("CARGO_MANIFEST_DIR")
+        .map(|dir| std::path::Path::new(&dir).join("src"))
+        .expect("CARGO_MANIFEST_DIR is set by cargo");
+    match days::find(&src) {
+        Ok(days) => {
+            let modules = days.iter().map(|day| format_ident!("day{}", day));
+            quote!(vec![#(#modules::solution()),*]).into()
+        }
+        Err(err) => syn::Error::new(
+            Span::call_site(),
+            format!("Listing the days in {}: {}", src.display(), err),
+        )
+        .to_compile_error()
+        .into(),
+    }
+}
 
-            #part2
+/// Derives `FromStr` from a pattern of literal text and `{field}`
+/// placeholders, `{0}` for tuple structs:
+///
+/// ```ignore
+/// #[derive(AocParse)]
+/// #[aoc(pattern = "move {quantity} from {from} to {to}")]
+/// pub struct Move {
+///     quantity: usize,
+///     from: usize,
+///     to: usize,
+/// }
+/// ```
+///
+/// Each field is parsed with its own `FromStr`, up to the text following its
+/// placeholder. Errors are `aoc_common::parse::ParseError`s pointing at the
+/// offending column, so the crate must depend on `aoc-common`.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn aoc_parse(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    match AocPattern::parse(&input) {
+        Ok(pattern) => generate_aoc_parse(&input, pattern).into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
-            #fn_body
+fn generate_aoc_parse(input: &DeriveInput, pattern: AocPattern) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let var = |index: usize| format_ident!("field_{}", index);
 
-        }
+    let steps = pattern
+        .segments
+        .iter()
+        .enumerate()
+        .map(|(position, segment)| match segment {
+            Segment::Literal(literal) => quote! { scanner.literal(#literal)?; },
+            Segment::Field(index) => {
+                let field = &pattern.fields[*index];
+                let (var, ty, field_name) = (var(*index), &field.ty, field.name());
+                let until = match pattern.segments.get(position + 1) {
+                    Some(Segment::Literal(literal)) => quote! { Some(#literal) },
+                    _ => quote! { None },
+                };
+                quote! { let #var: #ty = scanner.field(#field_name, #until)?; }
+            }
+        });
+
+    let members = pattern.fields.iter().map(|field| &field.member);
+    let vars = (0..pattern.fields.len()).map(var);
+
+    quote! {
+        impl #impl_generics std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = aoc_common::parse::ParseError;
 
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                let mut scanner = aoc_common::pattern::Scanner::new(input);
+                #(#steps)*
+                scanner.finish()?;
+                Ok(#name { #(#members: #vars),* })
+            }
+        }
     }
 }
@@ -0,0 +1,297 @@
+use proc_macro2::Span;
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Fields, Index, Lit, LitStr, Member, Meta,
+    NestedMeta, Type,
+};
+
+use crate::args::Errors;
+
+/// Piece of a pattern, fields are indexes in [`AocPattern::fields`].
+#[derive(Debug, PartialEq)]
+pub enum Segment {
+    Literal(String),
+    Field(usize),
+}
+
+pub struct Field {
+    pub member: Member,
+    pub ty: Type,
+}
+
+impl Field {
+    /// Name of the field as written in the pattern.
+    pub fn name(&self) -> String {
+        match &self.member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+}
+
+/// Validated `#[aoc(pattern = "...")]` of `#[derive(AocParse)]`.
+pub struct AocPattern {
+    pub fields: Vec<Field>,
+    pub segments: Vec<Segment>,
+}
+
+impl AocPattern {
+    pub fn parse(input: &DeriveInput) -> syn::Result<AocPattern> {
+        let fields = match &input.data {
+            Data::Struct(data) if !matches!(data.fields, Fields::Unit) => data
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| Field {
+                    member: match &field.ident {
+                        Some(ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(Index::from(index)),
+                    },
+                    ty: field.ty.clone(),
+                })
+                .collect::<Vec<_>>(),
+            _ => {
+                return Err(Error::new(
+                    input.ident.span(),
+                    "`AocParse` can only be derived for structs with fields",
+                ))
+            }
+        };
+
+        let pattern = pattern_attribute(input)?;
+        let mut errors = Errors::default();
+        let mut segments = vec![];
+        let mut used = vec![false; fields.len()];
+
+        for piece in split(&pattern.value()).map_err(|msg| Error::new(pattern.span(), msg))? {
+            match piece {
+                Piece::Literal(literal) => segments.push(Segment::Literal(literal)),
+                Piece::Placeholder(name) => {
+                    match fields.iter().position(|field| field.name() == name) {
+                        Some(index) if used[index] => errors.push(Error::new(
+                            pattern.span(),
+                            format!("`{{{}}}` appears more than once", name),
+                        )),
+                        Some(index) => {
+                            used[index] = true;
+                            segments.push(Segment::Field(index));
+                        }
+                        None => errors.push(Error::new(
+                            pattern.span(),
+                            format!("no field `{}` in `{}`", name, input.ident),
+                        )),
+                    }
+                }
+            }
+        }
+        for (field, used) in fields.iter().zip(used) {
+            if !used {
+                errors.push(Error::new(
+                    pattern.span(),
+                    format!("field `{}` is missing from the pattern", field.name()),
+                ));
+            }
+        }
+
+        errors.finish()?;
+
+        Ok(AocPattern { fields, segments })
+    }
+}
+
+/// The `pattern` of the single `#[aoc(...)]` attribute.
+fn pattern_attribute(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut attributes = input.attrs.iter().filter(|attr| attr.path.is_ident("aoc"));
+    let attr = attributes.next().ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "missing `#[aoc(pattern = \"...\")]` for `AocParse`",
+        )
+    })?;
+    if let Some(duplicate) = attributes.next() {
+        return Err(Error::new(duplicate.span(), "duplicate `#[aoc(...)]`"));
+    }
+
+    match attr.parse_meta()? {
+        Meta::List(list) if list.nested.len() == 1 => match &list.nested[0] {
+            NestedMeta::Meta(Meta::NameValue(named)) if named.path.is_ident("pattern") => {
+                match &named.lit {
+                    Lit::Str(pattern) => Ok(pattern.clone()),
+                    lit => Err(Error::new(lit.span(), "expected a string literal")),
+                }
+            }
+            nested => Err(Error::new(nested.span(), "expected `pattern = \"...\"`")),
+        },
+        meta => Err(Error::new(
+            meta.span(),
+            "expected `#[aoc(pattern = \"...\")]`",
+        )),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// Splits a pattern into literals and `{field}` placeholders, `{{` and `}}`
+/// stand for literal braces.
+fn split(pattern: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = vec![];
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err("unclosed `{` in the pattern".to_string()),
+                    }
+                }
+                if name.is_empty() {
+                    return Err("empty placeholder `{}` in the pattern".to_string());
+                }
+                match pieces.last() {
+                    Some(Piece::Placeholder(previous)) if literal.is_empty() => {
+                        return Err(format!(
+                            "`{{{}}}` and `{{{}}}` must be separated by some text",
+                            previous, name
+                        ))
+                    }
+                    _ => {}
+                }
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(std::mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Placeholder(name));
+            }
+            '}' => return Err("unmatched `}` in the pattern, use `}}`".to_string()),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::{parse_quote, DeriveInput};
+
+    use super::{split, AocPattern, Piece, Segment};
+
+    fn errors(input: DeriveInput) -> Vec<String> {
+        match AocPattern::parse(&input) {
+            Ok(_) => vec![],
+            Err(errors) => errors.into_iter().map(|err| err.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            split("move {quantity} from {from} to {to}"),
+            Ok(vec![
+                Piece::Literal("move ".to_string()),
+                Piece::Placeholder("quantity".to_string()),
+                Piece::Literal(" from ".to_string()),
+                Piece::Placeholder("from".to_string()),
+                Piece::Literal(" to ".to_string()),
+                Piece::Placeholder("to".to_string()),
+            ])
+        );
+        assert_eq!(
+            split("{{{0}}}"),
+            Ok(vec![
+                Piece::Literal("{".to_string()),
+                Piece::Placeholder("0".to_string()),
+                Piece::Literal("}".to_string()),
+            ])
+        );
+
+        assert_eq!(
+            split("{low}{high}"),
+            Err("`{low}` and `{high}` must be separated by some text".to_string())
+        );
+        assert_eq!(
+            split("{low"),
+            Err("unclosed `{` in the pattern".to_string())
+        );
+        assert_eq!(
+            split("low}"),
+            Err("unmatched `}` in the pattern, use `}}`".to_string())
+        );
+        assert_eq!(
+            split("a {} b"),
+            Err("empty placeholder `{}` in the pattern".to_string())
+        );
+    }
+
+    #[test]
+    fn test_valid_pattern() {
+        let input: DeriveInput = parse_quote! {
+            #[aoc(pattern = "{0},{1}")]
+            struct Pair(u32, u32);
+        };
+
+        let pattern = AocPattern::parse(&input).unwrap();
+
+        assert_eq!(pattern.fields.len(), 2);
+        assert_eq!(
+            pattern.segments,
+            vec![
+                Segment::Field(0),
+                Segment::Literal(",".to_string()),
+                Segment::Field(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert_eq!(
+            errors(parse_quote! {
+                #[aoc(pattern = "{from} -> {from} {size}")]
+                struct Move { from: usize, to: usize }
+            }),
+            vec![
+                "`{from}` appears more than once",
+                "no field `size` in `Move`",
+                "field `to` is missing from the pattern",
+            ]
+        );
+        assert_eq!(
+            errors(parse_quote! {
+                struct Move { from: usize }
+            }),
+            vec!["missing `#[aoc(pattern = \"...\")]` for `AocParse`"]
+        );
+        assert_eq!(
+            errors(parse_quote! {
+                #[aoc(template = "{from}")]
+                struct Move { from: usize }
+            }),
+            vec!["expected `pattern = \"...\"`"]
+        );
+        assert_eq!(
+            errors(parse_quote! {
+                #[aoc(pattern = "x")]
+                enum Cell { Rock }
+            }),
+            vec!["`AocParse` can only be derived for structs with fields"]
+        );
+    }
+}
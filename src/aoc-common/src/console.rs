@@ -0,0 +1,438 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use anyhow::Context;
+
+/// Registers of the handheld game console.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Registers {
+    pub pc: usize,
+    pub acc: i64,
+}
+
+/// Instruction the console can run, puzzles extending the console implement
+/// it for their own instruction set, usually wrapping [`Op`].
+pub trait Instruction {
+    /// Applies the instruction, returning the offset of the next instruction
+    /// from the current one.
+    fn execute(&self, registers: &mut Registers) -> isize;
+}
+
+/// Instructions of the boot code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+impl Op {
+    /// The instruction swapped between `nop` and `jmp`, `None` for `acc`.
+    pub fn flipped(&self) -> Option<Op> {
+        match *self {
+            Op::Nop(arg) => Some(Op::Jmp(arg)),
+            Op::Jmp(arg) => Some(Op::Nop(arg)),
+            Op::Acc(_) => None,
+        }
+    }
+}
+
+impl Instruction for Op {
+    fn execute(&self, registers: &mut Registers) -> isize {
+        match *self {
+            Op::Nop(_) => 1,
+            Op::Acc(arg) => {
+                registers.acc += arg;
+                1
+            }
+            Op::Jmp(offset) => offset as isize,
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (op, arg) = s
+            .split_once(' ')
+            .with_context(|| format!("Invalid instruction {:?}", s))?;
+        let arg = arg
+            .parse()
+            .with_context(|| format!("Invalid argument {:?}", arg))?;
+        match op {
+            "nop" => Ok(Op::Nop(arg)),
+            "acc" => Ok(Op::Acc(arg)),
+            "jmp" => Ok(Op::Jmp(arg)),
+            _ => anyhow::bail!("Unknown operation {:?}", op),
+        }
+    }
+}
+
+/// Instructions of a program, one per line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program<I = Op>(pub Vec<I>);
+
+impl<I> FromStr for Program<I>
+where
+    I: FromStr,
+    I::Err: Into<anyhow::Error>,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse()
+                    .map_err(Into::<anyhow::Error>::into)
+                    .with_context(|| format!("Line {}", index + 1))
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(Program)
+    }
+}
+
+/// How a run of the console stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionResult {
+    /// Jumped right past the last instruction.
+    Halted,
+    /// About to run the instruction at `pc` a second time.
+    InfiniteLoop { pc: usize },
+    /// Jumped to `pc`, outside of the program.
+    OutOfBounds { pc: isize },
+    /// About to run the instruction at the breakpoint `pc`, running again
+    /// resumes from there.
+    Breakpoint { pc: usize },
+    /// Ran the maximum number of instructions allowed.
+    CycleLimit,
+}
+
+/// Instruction run by the console, with the registers before running it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub cycle: usize,
+    pub registers: Registers,
+}
+
+/// Console running a program, with some of its instructions optionally
+/// replaced by patches.
+///
+/// A program is deemed stuck in an infinite loop as soon as an instruction
+/// runs twice, which holds as long as the jumps do not depend on the
+/// registers.
+pub struct Console<'a, I = Op> {
+    program: &'a [I],
+    patches: HashMap<usize, I>,
+    registers: Registers,
+    visited: Vec<bool>,
+    cycles: usize,
+    cycle_limit: Option<usize>,
+    breakpoints: HashSet<usize>,
+    resume_at: Option<usize>,
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl<'a, I: Instruction> Console<'a, I> {
+    pub fn new(program: &'a Program<I>) -> Self {
+        Console {
+            program: &program.0,
+            patches: HashMap::new(),
+            registers: Registers::default(),
+            visited: vec![false; program.0.len()],
+            cycles: 0,
+            cycle_limit: None,
+            breakpoints: HashSet::new(),
+            resume_at: None,
+            trace: None,
+        }
+    }
+
+    /// Runs `instruction` in place of the one at `pc`.
+    pub fn with_patch(mut self, pc: usize, instruction: I) -> Self {
+        self.patches.insert(pc, instruction);
+        self
+    }
+
+    /// Stops the runs after `limit` instructions overall.
+    pub fn with_cycle_limit(mut self, limit: usize) -> Self {
+        self.cycle_limit = Some(limit);
+        self
+    }
+
+    /// Stops the runs before running the instruction at `pc`.
+    pub fn with_breakpoint(mut self, pc: usize) -> Self {
+        self.breakpoints.insert(pc);
+        self
+    }
+
+    /// Records every instruction run, see [`Console::trace`].
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    /// Number of instructions run so far.
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Instructions run so far, empty unless built [`Console::with_trace`].
+    pub fn trace(&self) -> &[TraceEntry] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// Instruction at `pc`, patched or not.
+    pub fn instruction(&self, pc: usize) -> Option<&I> {
+        self.patches.get(&pc).or_else(|| self.program.get(pc))
+    }
+
+    /// Runs the program until it stops, see [`ExecutionResult`].
+    pub fn run(&mut self) -> ExecutionResult {
+        loop {
+            if let Some(result) = self.step() {
+                return result;
+            }
+        }
+    }
+
+    /// Runs a single instruction, `None` unless the program stops.
+    pub fn step(&mut self) -> Option<ExecutionResult> {
+        let pc = self.registers.pc;
+        if pc == self.program.len() {
+            return Some(ExecutionResult::Halted);
+        }
+        if self.breakpoints.contains(&pc) && self.resume_at.take() != Some(pc) {
+            self.resume_at = Some(pc);
+            return Some(ExecutionResult::Breakpoint { pc });
+        }
+        if self.visited[pc] {
+            return Some(ExecutionResult::InfiniteLoop { pc });
+        }
+        if self.cycle_limit.is_some_and(|limit| self.cycles >= limit) {
+            return Some(ExecutionResult::CycleLimit);
+        }
+
+        let instruction = match self.patches.get(&pc) {
+            Some(patch) => patch,
+            None => &self.program[pc],
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceEntry {
+                cycle: self.cycles,
+                registers: self.registers,
+            });
+        }
+        let offset = instruction.execute(&mut self.registers);
+        self.visited[pc] = true;
+        self.cycles += 1;
+
+        let next = pc as isize + offset;
+        match usize::try_from(next) {
+            Ok(next) if next <= self.program.len() => {
+                self.registers.pc = next;
+                None
+            }
+            _ => Some(ExecutionResult::OutOfBounds { pc: next }),
+        }
+    }
+}
+
+/// Instruction flipped between `nop` and `jmp` to make the boot code halt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fix {
+    pub pc: usize,
+    pub op: Op,
+}
+
+/// Strategy finding the single [`Fix`] of a looping boot code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repair {
+    /// Runs the program with each flip in turn, quadratic.
+    BruteForce,
+    /// Finds the instructions reaching the end of the program, then flips
+    /// the first instruction of the looping run that jumps into them, linear.
+    Analytic,
+}
+
+impl Repair {
+    pub fn find(self, program: &Program) -> Option<Fix> {
+        match self {
+            Repair::BruteForce => brute_force(program),
+            Repair::Analytic => analytic(program),
+        }
+    }
+}
+
+fn brute_force(program: &Program) -> Option<Fix> {
+    program
+        .0
+        .iter()
+        .enumerate()
+        .filter_map(|(pc, op)| {
+            Some(Fix {
+                pc,
+                op: op.flipped()?,
+            })
+        })
+        .find(|fix| {
+            let mut console = Console::new(program).with_patch(fix.pc, fix.op);
+            console.run() == ExecutionResult::Halted
+        })
+}
+
+fn analytic(program: &Program) -> Option<Fix> {
+    let len = program.0.len();
+    // Next instruction of `op` at `pc`, `len` being the end of the program
+    let next = |pc: usize, op: &Op| {
+        usize::try_from(pc as isize + op.execute(&mut Registers::default()))
+            .ok()
+            .filter(|next| *next <= len)
+    };
+
+    let mut sources = vec![vec![]; len + 1];
+    for (pc, op) in program.0.iter().enumerate() {
+        if let Some(next) = next(pc, op) {
+            sources[next].push(pc);
+        }
+    }
+    let mut halts = vec![false; len + 1];
+    halts[len] = true;
+    let mut pending = vec![len];
+    while let Some(pc) = pending.pop() {
+        for &source in &sources[pc] {
+            if !halts[source] {
+                halts[source] = true;
+                pending.push(source);
+            }
+        }
+    }
+
+    // The run from the flip never goes back through it, otherwise the
+    // original run would have halted too
+    let mut console = Console::new(program).with_trace();
+    console.run();
+    console.trace().iter().find_map(|entry| {
+        let pc = entry.registers.pc;
+        let op = program.0[pc].flipped()?;
+        next(pc, &op)
+            .filter(|next| halts[*next])
+            .map(|_| Fix { pc, op })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Console, ExecutionResult, Fix, Op, Program, Registers, Repair};
+
+    const BOOT: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn test_parse() {
+        let program: Program = BOOT.parse().unwrap();
+
+        assert_eq!(program.0.len(), 9);
+        assert_eq!(program.0[4], Op::Jmp(-3));
+
+        let err = "nop +0\nmul +2\n".parse::<Program>().unwrap_err();
+        assert_eq!(format!("{:#}", err), "Line 2: Unknown operation \"mul\"");
+    }
+
+    #[test]
+    fn test_infinite_loop_and_patch() {
+        let program: Program = BOOT.parse().unwrap();
+
+        let mut console = Console::new(&program);
+        assert_eq!(console.run(), ExecutionResult::InfiniteLoop { pc: 1 });
+        assert_eq!(console.registers().acc, 5);
+
+        let mut patched = Console::new(&program).with_patch(7, Op::Nop(-4));
+        assert_eq!(patched.run(), ExecutionResult::Halted);
+        assert_eq!(patched.registers(), Registers { pc: 9, acc: 8 });
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let program = Program(vec![Op::Acc(1), Op::Jmp(-2)]);
+        assert_eq!(
+            Console::new(&program).run(),
+            ExecutionResult::OutOfBounds { pc: -1 }
+        );
+
+        let program = Program(vec![Op::Jmp(3)]);
+        assert_eq!(
+            Console::new(&program).run(),
+            ExecutionResult::OutOfBounds { pc: 3 }
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_limits_and_trace() {
+        let program: Program = BOOT.parse().unwrap();
+
+        let mut console = Console::new(&program).with_breakpoint(3).with_trace();
+        assert_eq!(console.run(), ExecutionResult::Breakpoint { pc: 3 });
+        assert_eq!(console.registers().acc, 2);
+        assert_eq!(console.run(), ExecutionResult::InfiniteLoop { pc: 1 });
+        assert_eq!(
+            console
+                .trace()
+                .iter()
+                .map(|entry| entry.registers.pc)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 6, 7, 3, 4]
+        );
+
+        let mut limited = Console::new(&program).with_cycle_limit(4);
+        assert_eq!(limited.run(), ExecutionResult::CycleLimit);
+        assert_eq!(limited.cycles(), 4);
+        assert_eq!(limited.registers(), Registers { pc: 7, acc: 2 });
+    }
+
+    #[test]
+    fn test_repair() {
+        let program: Program = BOOT.parse().unwrap();
+        let fix = Fix {
+            pc: 7,
+            op: Op::Nop(-4),
+        };
+
+        assert_eq!(Repair::BruteForce.find(&program), Some(fix));
+        assert_eq!(Repair::Analytic.find(&program), Some(fix));
+
+        // Long chain of nops looping back only at its end
+        let mut ops = vec![Op::Jmp(2), Op::Acc(1)];
+        ops.extend((0..100).map(|i| if i % 3 == 0 { Op::Acc(i) } else { Op::Nop(0) }));
+        ops.push(Op::Jmp(-100));
+        ops.push(Op::Acc(7));
+        let program = Program(ops);
+
+        let expected = Some(Fix {
+            pc: 102,
+            op: Op::Nop(-100),
+        });
+        assert_eq!(Repair::BruteForce.find(&program), expected);
+        assert_eq!(Repair::Analytic.find(&program), expected);
+
+        let program = Program(vec![Op::Jmp(1), Op::Jmp(-1), Op::Jmp(-2)]);
+        assert_eq!(Repair::BruteForce.find(&program), None);
+        assert_eq!(Repair::Analytic.find(&program), None);
+    }
+}
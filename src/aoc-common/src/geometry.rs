@@ -0,0 +1,251 @@
+use std::ops::{Add, Sub};
+
+/// Integer coordinate of a [`Point`].
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    /// Absolute difference between two coordinates.
+    fn distance(self, other: Self) -> Self;
+
+    /// Coordinate moved by `delta`, `None` when it does not fit the type.
+    fn checked_offset(self, delta: isize) -> Option<Self>;
+}
+
+macro_rules! unsigned_coordinate {
+    ($($ty:ty),*) => {$(
+        impl Coordinate for $ty {
+            fn distance(self, other: Self) -> Self {
+                self.abs_diff(other)
+            }
+
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                let magnitude = <$ty>::try_from(delta.unsigned_abs()).ok()?;
+                if delta < 0 {
+                    self.checked_sub(magnitude)
+                } else {
+                    self.checked_add(magnitude)
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_coordinate {
+    ($($ty:ty),*) => {$(
+        impl Coordinate for $ty {
+            fn distance(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+
+            fn checked_offset(self, delta: isize) -> Option<Self> {
+                self.checked_add(<$ty>::try_from(delta).ok()?)
+            }
+        }
+    )*};
+}
+
+unsigned_coordinate!(usize, u32, u64);
+signed_coordinate!(isize, i32, i64);
+
+/// Point of a plane, rows grow downwards and columns to the right.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub row: T,
+    pub col: T,
+}
+
+/// Cell of a [`crate::Grid`].
+pub type Position = Point<usize>;
+
+impl<T> Point<T> {
+    pub const fn new(row: T, col: T) -> Self {
+        Self { row, col }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// Next point towards `direction`, `None` when it does not fit the
+    /// coordinates, like stepping before the first row of a [`Position`].
+    pub fn step(&self, direction: impl Into<Direction8>) -> Option<Self> {
+        self.offset(direction.into().offset())
+    }
+
+    /// Point moved by the given row and column deltas, `None` when it does
+    /// not fit the coordinates.
+    pub fn offset(&self, (row, col): (isize, isize)) -> Option<Self> {
+        Some(Point::new(
+            self.row.checked_offset(row)?,
+            self.col.checked_offset(col)?,
+        ))
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.row.distance(other.row) + self.col.distance(other.col)
+    }
+
+    /// Distance counting diagonal steps as one, like a king on a chessboard.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.row
+            .distance(other.row)
+            .max(self.col.distance(other.col))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Self) -> Self::Output {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All the directions, clockwise from `Up`.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Direction after turning right.
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Direction after turning left.
+    pub fn counter_clockwise(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Row and column deltas of a step.
+    pub fn offset(self) -> (isize, isize) {
+        Direction8::from(self).offset()
+    }
+}
+
+/// Directions including the diagonal ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All the directions, clockwise from `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Direction after turning right by 45 degrees.
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Direction after turning left by 45 degrees.
+    pub fn counter_clockwise(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Row and column deltas of a step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (-1, 0),
+            Direction8::UpRight => (-1, 1),
+            Direction8::Right => (0, 1),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (1, 0),
+            Direction8::DownLeft => (1, -1),
+            Direction8::Left => (0, -1),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction4, Direction8, Point, Position};
+
+    #[test]
+    fn test_step() {
+        let origin = Position::new(0, 0);
+
+        assert_eq!(origin.step(Direction4::Down), Some(Position::new(1, 0)));
+        assert_eq!(origin.step(Direction4::Right), Some(Position::new(0, 1)));
+        assert_eq!(origin.step(Direction4::Up), None);
+        assert_eq!(origin.step(Direction8::DownLeft), None);
+        assert_eq!(
+            Position::new(2, 3).step(Direction8::UpLeft),
+            Some(Position::new(1, 2))
+        );
+        assert_eq!(
+            Point::new(0, 0).step(Direction4::Up),
+            Some(Point::new(-1, 0))
+        );
+        assert_eq!(Point::new(0, i32::MAX).step(Direction4::Right), None);
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Direction4::Left.clockwise(), Direction4::Up);
+        assert_eq!(Direction4::Up.counter_clockwise(), Direction4::Left);
+        assert_eq!(Direction4::Right.opposite(), Direction4::Left);
+        assert_eq!(Direction8::UpLeft.clockwise(), Direction8::Up);
+        assert_eq!(Direction8::Up.counter_clockwise(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction4::Left), Direction8::Left);
+    }
+
+    #[test]
+    fn test_distances_and_ops() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(b - a, Point::new(-4, 6));
+        assert_eq!(Position::new(5, 1).manhattan(&Position::new(2, 3)), 5);
+    }
+}
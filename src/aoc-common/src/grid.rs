@@ -0,0 +1,412 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, bail};
+
+use crate::geometry::{Direction4, Direction8, Position};
+
+/// Rectangular grid of cells, addressed by [`Position`] and stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given rows, failing if they are not all of the same width.
+    pub fn new(rows: Vec<Vec<T>>) -> anyhow::Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(row) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "Row {} has {} cells, expected {}",
+                row,
+                rows[row].len(),
+                width
+            );
+        }
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid of one character per cell and one line per row.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(Position, char) -> anyhow::Result<T>,
+    ) -> anyhow::Result<Self> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| cell(Position::new(row, col), c))
+                    .collect()
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Grid::new(rows)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn is_edge(&self, pos: Position) -> bool {
+        pos.row == 0 || pos.col == 0 || pos.row + 1 == self.height || pos.col + 1 == self.width
+    }
+
+    fn offset(&self, pos: Position) -> Option<usize> {
+        self.contains(pos).then(|| pos.row * self.width + pos.col)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.offset(pos).map(move |offset| &mut self.cells[offset])
+    }
+
+    /// Appends a row at the bottom, panics unless it is as wide as the grid.
+    pub fn push_row(&mut self, row: Vec<T>) {
+        if self.height == 0 {
+            self.width = row.len();
+        }
+        assert_eq!(row.len(), self.width, "pushing a row of another width");
+        self.cells.extend(row);
+        self.height += 1;
+    }
+
+    /// Next position towards `direction`, `None` when leaving the grid.
+    pub fn step(&self, pos: Position, direction: impl Into<Direction8>) -> Option<Position> {
+        pos.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// Up, right, down and left neighbours inside the grid.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Neighbours inside the grid including the diagonal ones, clockwise
+    /// from up.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// Cells from `pos` excluded towards `direction`, up to the edge.
+    pub fn ray(
+        &self,
+        pos: Position,
+        direction: impl Into<Direction8>,
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let direction = direction.into();
+        std::iter::successors(self.step(pos, direction), move |next| {
+            self.step(*next, direction)
+        })
+        .map(move |next| (next, &self[next]))
+    }
+
+    /// Cells of a row, empty past the last row.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        let cells = if row < self.height {
+            &self.cells[row * self.width..(row + 1) * self.width]
+        } else {
+            &[]
+        };
+        cells.iter()
+    }
+
+    /// Cells of a column, empty past the last column.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).filter_map(move |row| self.get(Position::new(row, col)))
+    }
+
+    /// Cells with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| (Position::new(offset / width, offset % width), cell))
+    }
+
+    /// Positions of the cells matching the predicate, row by row.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Grid of the same size with `f` applied to each cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Displays the grid drawing each cell with `renderer`, one line per row.
+    pub fn display<R, F>(&self, renderer: F) -> Render<'_, T, F>
+    where
+        F: Fn(&T) -> R,
+        R: Display,
+    {
+        Render {
+            grid: self,
+            renderer,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid of `height` rows of `width` copies of `value`.
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+/// Parses a char map whose characters convert into cells.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |pos, c| {
+            T::try_from(c)
+                .map_err(|err| anyhow!("Invalid cell {} at ({},{}): {}", c, pos.row, pos.col, err))
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        match self.offset(pos) {
+            Some(offset) => &self.cells[offset],
+            None => panic!("{:?} is outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        match self.offset(pos) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!("{:?} is outside of the grid", pos),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.height {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// [`Grid`] drawn by a per-cell renderer, see [`Grid::display`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    renderer: F,
+}
+
+impl<'a, T, F, R> Display for Render<'a, T, F>
+where
+    F: Fn(&T) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.grid.height {
+            for cell in self.grid.row(row) {
+                write!(f, "{}", (self.renderer)(cell))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::geometry::{Direction4, Position};
+
+    fn digits(input: &str) -> anyhow::Result<Grid<u32>> {
+        Grid::parse(input, |pos, c| {
+            c.to_digit(10)
+                .ok_or_else(|| anyhow::anyhow!("Invalid digit {} at {:?}", c, pos))
+        })
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = String;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err("expected . or #".to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[Position::new(1, 2)], 6);
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 3)), None);
+        assert_eq!(
+            digits("12\n3x\n").unwrap_err().to_string(),
+            "Invalid digit x at Point { row: 1, col: 1 }"
+        );
+        assert_eq!(
+            digits("12\n3\n").unwrap_err().to_string(),
+            "Row 1 has 1 cells, expected 2"
+        );
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        let mut grid: Grid<Tile> = ".#.\n#..\n".parse().unwrap();
+        *grid.get_mut(Position::new(1, 2)).unwrap() = Tile::Wall;
+
+        assert_eq!(grid[Position::new(0, 1)], Tile::Wall);
+        assert_eq!(
+            grid.display(|tile| match tile {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            })
+            .to_string(),
+            ".#.\n#.#\n"
+        );
+        assert_eq!(
+            ".#\n.x\n".parse::<Grid<Tile>>().unwrap_err().to_string(),
+            "Invalid cell x at (1,1): expected . or #"
+        );
+        assert_eq!(digits("12\n34").unwrap().to_string(), "12\n34\n");
+        assert_eq!(
+            grid.map(|tile| *tile == Tile::Wall).to_string(),
+            "falsetruefalse\ntruefalsetrue\n"
+        );
+    }
+
+    #[test]
+    fn test_step_and_edges() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        let center = Position::new(1, 1);
+        let corner = Position::new(2, 2);
+
+        assert!(!grid.is_edge(center));
+        assert!(grid.is_edge(corner));
+        assert_eq!(grid.step(corner, Direction4::Up), Some(Position::new(1, 2)));
+        assert_eq!(grid.step(corner, Direction4::Down), None);
+        assert_eq!(grid.step(corner, Direction4::Right), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789\n").unwrap();
+        let values = |positions: Vec<Position>| {
+            positions
+                .into_iter()
+                .map(|pos| grid[pos])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            values(grid.neighbours(Position::new(1, 1)).collect()),
+            vec![2, 6, 8, 4]
+        );
+        assert_eq!(
+            values(grid.neighbours(Position::new(0, 0)).collect()),
+            vec![2, 4]
+        );
+        assert_eq!(
+            values(grid.neighbours8(Position::new(1, 1)).collect()),
+            vec![2, 3, 6, 9, 8, 7, 4, 1]
+        );
+        assert_eq!(
+            values(grid.neighbours8(Position::new(2, 2)).collect()),
+            vec![6, 8, 5]
+        );
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let mut grid = digits("123\n456\n").unwrap();
+        grid.push_row(vec![7, 8, 9]);
+
+        assert_eq!(grid.row(2).copied().collect::<Vec<_>>(), vec![7, 8, 9]);
+        assert_eq!(grid.row(3).count(), 0);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5, 8]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.ray(Position::new(2, 2), Direction4::Left)
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>(),
+            vec![8, 7]
+        );
+        assert_eq!(grid.ray(Position::new(0, 1), Direction4::Up).count(), 0);
+    }
+
+    #[test]
+    fn test_positions() {
+        let mut grid = digits("121\n212\n").unwrap();
+        grid[Position::new(1, 1)] = 2;
+
+        assert_eq!(
+            grid.positions(|cell| *cell == 2).collect::<Vec<_>>(),
+            vec![
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(1, 1),
+                Position::new(1, 2)
+            ]
+        );
+        assert_eq!(Grid::filled(2, 3, 0).row(1).count(), 3);
+    }
+}
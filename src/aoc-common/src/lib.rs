@@ -0,0 +1,11 @@
+pub mod console;
+pub mod geometry;
+pub mod grid;
+pub mod parse;
+pub mod pattern;
+pub mod search;
+pub mod sparse;
+
+pub use geometry::{Direction4, Direction8, Point, Position};
+pub use grid::{Grid, Render};
+pub use sparse::{Bounds, SparseGrid, SparseRender};
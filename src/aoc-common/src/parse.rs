@@ -0,0 +1,468 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, one_of},
+    combinator::{map_res, opt, recognize},
+    error::Error,
+    sequence::{pair, separated_pair},
+    IResult,
+};
+
+use crate::grid::Grid;
+
+pub fn num_parser<T>() -> impl FnMut(&str) -> IResult<&str, T>
+where
+    T: FromStr,
+{
+    move |input| map_res(recognize(digit1), str::parse)(input)
+}
+
+/// Number with an optional `+` or `-` sign.
+pub fn signed_parser<T>() -> impl FnMut(&str) -> IResult<&str, T>
+where
+    T: FromStr,
+{
+    move |input| map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Two numbers around a separator, like the `498,4` coordinates.
+pub fn pair_parser<'a, T>(separator: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, (T, T)>
+where
+    T: FromStr,
+{
+    move |input| separated_pair(num_parser(), tag(separator), num_parser())(input)
+}
+
+/// Failure to parse the input, located by line and column, both counted
+/// from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The line of the input where parsing failed.
+    pub excerpt: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error at the start of `at`, which must be a slice of `input`.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("the error location is a slice of the input");
+        let before = &input[..offset];
+        let start = before.rfind('\n').map_or(0, |newline| newline + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[start..].chars().count() + 1,
+            excerpt: input[start..]
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The same error located in `outer`, when the input parsed was its
+    /// `inner` slice.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let start = ParseError::at(outer, inner, "");
+        if self.line == 1 {
+            ParseError {
+                column: start.column + self.column - 1,
+                message: self.message,
+                ..start
+            }
+        } else {
+            ParseError {
+                line: start.line + self.line - 1,
+                ..self
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.excerpt)?;
+        write!(f, "  {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `slice` of `input` entirely, locating errors in `input`.
+fn parse_slice<'a, T>(
+    input: &'a str,
+    slice: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    match parser(slice) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(ParseError::at(input, rest, "unexpected trailing input")),
+        Err(nom::Err::Error(Error { input: at, code }))
+        | Err(nom::Err::Failure(Error { input: at, code })) => Err(ParseError::at(
+            input,
+            at,
+            format!("unexpected input, {} failed", code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            input,
+            &slice[slice.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+/// Parses the whole input, failing on any leftover.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    parse_slice(input, input, parser)
+}
+
+/// Parses each line of the input entirely.
+pub fn lines_of<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| parse_slice(input, line, &mut parser))
+        .collect()
+}
+
+/// Parses each block of lines of the input entirely, blocks are separated by
+/// blank lines and handed to the parser without their last newline.
+pub fn blocks_of<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_end_matches('\n'))
+        .filter(|block| !block.is_empty())
+        .map(|block| parse_slice(input, block, &mut parser))
+        .collect()
+}
+
+/// Parses a grid of one line per row, applying `cell` until the end of each
+/// line.
+pub fn grid_of<'a, T>(
+    input: &'a str,
+    mut cell: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut rows: Vec<Vec<T>> = vec![];
+    for line in input.lines() {
+        let mut row = vec![];
+        let mut rest = line;
+        while !rest.is_empty() {
+            let (next, value) = cell(rest).map_err(|_| {
+                ParseError::at(input, rest, "unexpected input, the cell parser failed")
+            })?;
+            if next.len() == rest.len() {
+                return Err(ParseError::at(
+                    input,
+                    rest,
+                    "the cell parser consumed nothing",
+                ));
+            }
+            row.push(value);
+            rest = next;
+        }
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("{} cells in the row, expected {}", row.len(), first.len()),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    Ok(Grid::new(rows).expect("the rows are checked"))
+}
+
+/// Line of the input rejected by its parser, counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    pub line: usize,
+    /// Column in the line, when the parser located the error.
+    pub column: Option<usize>,
+    pub content: String,
+    pub message: String,
+}
+
+impl LineError {
+    fn new(line: usize, content: &str, err: &impl LineFailure) -> Self {
+        LineError {
+            line,
+            column: err.column(content),
+            content: content.to_string(),
+            message: err.message(),
+        }
+    }
+}
+
+/// Error of a line parser given to [`parse_lines`], which may know the
+/// column of the failure in the line.
+pub trait LineFailure: Display {
+    /// Column of the failure in `line`, counted from 1.
+    fn column(&self, _line: &str) -> Option<usize> {
+        None
+    }
+
+    /// The failure, without its location.
+    fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Errors of the line, from [`parse_all`] or the derived patterns.
+impl LineFailure for ParseError {
+    fn column(&self, _line: &str) -> Option<usize> {
+        Some(self.column)
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+/// Errors of a nom parser applied to the line.
+impl LineFailure for nom::Err<Error<&str>> {
+    fn column(&self, line: &str) -> Option<usize> {
+        match self {
+            nom::Err::Error(Error { input: rest, .. })
+            | nom::Err::Failure(Error { input: rest, .. }) => {
+                let parsed = line.strip_suffix(rest)?;
+                Some(parsed.chars().count() + 1)
+            }
+            nom::Err::Incomplete(_) => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            nom::Err::Error(Error { code, .. }) | nom::Err::Failure(Error { code, .. }) => {
+                format!("unexpected input, {} failed", code.description())
+            }
+            nom::Err::Incomplete(_) => "unexpected end of input".to_string(),
+        }
+    }
+}
+
+impl LineFailure for ParseIntError {}
+
+impl LineFailure for anyhow::Error {}
+
+/// Every line rejected by [`parse_lines`] in strict mode, out of `total`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineErrors {
+    pub total: usize,
+    pub errors: Vec<LineError>,
+}
+
+impl Display for LineErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} lines failed to parse",
+            self.errors.len(),
+            self.total
+        )?;
+        for error in &self.errors {
+            write!(f, "\n  line {}", error.line)?;
+            if let Some(column) = error.column {
+                write!(f, ", column {}", column)?;
+            }
+            write!(f, ": {:?}: {}", error.content, error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LineErrors {}
+
+/// Parses each line of the input with `parse`.
+///
+/// Lines failing to parse are skipped, unless `strict` where every failure is
+/// reported instead of a partial result. The runners hand their `--strict`
+/// flag to the generators taking it.
+pub fn parse_lines<'a, T, E: LineFailure>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, E>,
+    strict: bool,
+) -> Result<Vec<T>, LineErrors> {
+    let mut values = vec![];
+    let mut errors = vec![];
+    let mut total = 0;
+    for (index, line) in input.lines().enumerate() {
+        total += 1;
+        match parse(line) {
+            Ok(value) => values.push(value),
+            Err(err) if strict => errors.push(LineError::new(index + 1, line, &err)),
+            Err(_) => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(LineErrors { total, errors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{newline, one_of},
+        multi::separated_list1,
+    };
+
+    use super::{
+        blocks_of, grid_of, lines_of, num_parser, pair_parser, parse_all, parse_lines,
+        signed_parser, LineError, ParseError,
+    };
+    use crate::geometry::Position;
+
+    #[test]
+    fn test_num_parser() {
+        assert_eq!(num_parser::<u8>()("42 bags"), Ok((" bags", 42)));
+        assert!(num_parser::<u8>()("256").is_err());
+        assert!(num_parser::<u8>()("-1").is_err());
+    }
+
+    #[test]
+    fn test_signed_parser() {
+        assert_eq!(signed_parser::<i32>()("-17,3"), Ok((",3", -17)));
+        assert_eq!(signed_parser::<i32>()("+4"), Ok(("", 4)));
+        assert!(signed_parser::<i32>()("-").is_err());
+        assert!(signed_parser::<u32>()("-4").is_err());
+    }
+
+    #[test]
+    fn test_pair_parser() {
+        assert_eq!(
+            pair_parser::<usize>(",")("498,4 -> 498,6"),
+            Ok((" -> 498,6", (498, 4)))
+        );
+        assert!(pair_parser::<usize>(",")("498;4").is_err());
+    }
+
+    #[test]
+    fn test_lines_of() {
+        let point = || pair_parser::<i32>(",");
+
+        assert_eq!(lines_of("1,2\n3,4\n", point()), Ok(vec![(1, 2), (3, 4)]));
+
+        let err = lines_of("1,2\n3,x\n5,6\n", point()).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 2,
+                column: 3,
+                excerpt: "3,x".to_string(),
+                message: "unexpected input, Digit failed".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: unexpected input, Digit failed\n  3,x\n    ^"
+        );
+
+        let err = parse_all("1,2 and more", point()).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.message, "unexpected trailing input");
+
+        let input = "points: 1,2\n3,x\n";
+        let err = lines_of(&input[8..], point()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = lines_of(&input[8..], num_parser::<i32>())
+            .unwrap_err()
+            .within(input, &input[8..]);
+        assert_eq!((err.line, err.column), (1, 10));
+        assert_eq!(err.excerpt, "points: 1,2");
+    }
+
+    #[test]
+    fn test_blocks_of() {
+        let numbers = || separated_list1(newline, num_parser::<u32>());
+
+        assert_eq!(
+            blocks_of("1\n2\n\n3\n\n4\n5\n", numbers()),
+            Ok(vec![vec![1, 2], vec![3], vec![4, 5]])
+        );
+
+        let err = blocks_of("1\n\n2\n3x\n\n4\n", numbers()).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 2));
+        assert_eq!(err.message, "unexpected trailing input");
+    }
+
+    #[test]
+    fn test_grid_of() {
+        let grid = grid_of("#.#\n..#\n", one_of(".#")).unwrap();
+        assert_eq!(grid[Position::new(1, 2)], '#');
+
+        let err = grid_of("#.#\n.x#\n", one_of(".#")).unwrap_err();
+        assert_eq!((err.line, err.column, err.excerpt.as_str()), (2, 2, ".x#"));
+
+        let err = grid_of("#.#\n.#\n", one_of(".#")).unwrap_err();
+        assert_eq!(err.message, "2 cells in the row, expected 3");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1\nx\n3\n\n5\n";
+
+        assert_eq!(
+            parse_lines(input, str::parse::<u32>, false),
+            Ok(vec![1, 3, 5])
+        );
+
+        let err = parse_lines(input, str::parse::<u32>, true).unwrap_err();
+        assert_eq!(err.total, 5);
+        assert_eq!(
+            err.errors[0],
+            LineError {
+                line: 2,
+                column: None,
+                content: "x".to_string(),
+                message: "invalid digit found in string".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "2 of 5 lines failed to parse\n  \
+             line 2: \"x\": invalid digit found in string\n  \
+             line 4: \"\": cannot parse integer from empty string"
+        );
+
+        let point = |line| parse_all(line, pair_parser::<u32>(","));
+        let err = parse_lines("1,2\n3,x\n", point, true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "1 of 2 lines failed to parse\n  \
+             line 2, column 3: \"3,x\": unexpected input, Digit failed"
+        );
+
+        let mut point = pair_parser::<u32>(",");
+        let err = parse_lines("1,2\n3,x\n", |line| point(line).map(|(_, p)| p), true);
+        assert_eq!(err.unwrap_err().errors[0].column, Some(3));
+    }
+}
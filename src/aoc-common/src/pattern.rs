@@ -0,0 +1,140 @@
+use std::{any::Any, fmt::Display, str::FromStr};
+
+use crate::parse::ParseError;
+
+/// Walks an input along the pattern of a `#[derive(AocParse)]`, consuming
+/// literals and fields in turn.
+pub struct Scanner<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Scanner { input, rest: input }
+    }
+
+    /// Consumes `literal`, which must come next.
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        match self.rest.strip_prefix(literal) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(ParseError::at(
+                self.input,
+                self.rest,
+                format!("expected {:?}", literal),
+            )),
+        }
+    }
+
+    /// Parses the field `name` from the text up to the first `until`, or up
+    /// to the end of the input without it. `until` itself is left to be
+    /// consumed.
+    ///
+    /// Errors of fields parsed by a pattern themselves are located in the
+    /// whole input rather than wrapped.
+    pub fn field<T>(&mut self, name: &str, until: Option<&str>) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display + 'static,
+    {
+        let end = match until {
+            Some(until) => self.rest.find(until).ok_or_else(|| {
+                ParseError::at(
+                    self.input,
+                    &self.rest[self.rest.len()..],
+                    format!("expected {:?} after `{}`", until, name),
+                )
+            })?,
+            None => self.rest.len(),
+        };
+        let (value, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        value.parse().map_err(
+            |err| match (&err as &dyn Any).downcast_ref::<ParseError>() {
+                Some(nested) => nested.clone().within(self.input, value),
+                None => ParseError::at(self.input, value, format!("invalid `{}`: {}", name, err)),
+            },
+        )
+    }
+
+    /// Checks that the whole input was consumed.
+    pub fn finish(self) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(ParseError::at(
+                self.input,
+                self.rest,
+                "unexpected trailing input",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scanner;
+    use crate::parse::ParseError;
+
+    fn movement(input: &str) -> Result<(usize, u8, u8), ParseError> {
+        let mut scanner = Scanner::new(input);
+        scanner.literal("move ")?;
+        let quantity = scanner.field("quantity", Some(" from "))?;
+        scanner.literal(" from ")?;
+        let from = scanner.field("from", Some(" to "))?;
+        scanner.literal(" to ")?;
+        let to = scanner.field("to", None)?;
+        scanner.finish()?;
+        Ok((quantity, from, to))
+    }
+
+    #[test]
+    fn test_scanner() {
+        assert_eq!(movement("move 12 from 2 to 1"), Ok((12, 2, 1)));
+
+        let err = movement("move 12 from 2 to 300").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 19));
+        assert_eq!(
+            err.message,
+            "invalid `to`: number too large to fit in target type"
+        );
+
+        let err = movement("move 12 from 2").unwrap_err();
+        assert_eq!(err.column, 15);
+        assert_eq!(err.message, "expected \" to \" after `from`");
+
+        let err = movement("lift 12 from 2 to 1").unwrap_err();
+        assert_eq!(err.column, 1);
+        assert_eq!(err.message, "expected \"move \"");
+    }
+
+    #[test]
+    fn test_nested_field() {
+        let mut scanner = Scanner::new("stacks 1-2");
+        scanner.literal("stacks ").unwrap();
+        let err = scanner.field::<BadRange>("pair", None).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 10));
+        assert_eq!(err.message, "invalid `to`: invalid digit found in string");
+        assert_eq!(err.excerpt, "stacks 1-2");
+    }
+
+    /// Fails like a `{from}-{to}` pattern with an invalid `to`.
+    #[derive(Debug)]
+    struct BadRange;
+
+    impl std::str::FromStr for BadRange {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Err(ParseError::at(
+                s,
+                &s[2..],
+                "invalid `to`: invalid digit found in string",
+            ))
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cheapest path found by a search, from one of the starts to the goal, both
+/// included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Nodes reached so far, each with the index of the node it was reached from.
+struct Visited<N> {
+    nodes: Vec<(N, Option<usize>)>,
+    indexes: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new() -> Self {
+        Visited {
+            nodes: vec![],
+            indexes: HashMap::new(),
+        }
+    }
+
+    /// Index of the node, `None` when it was already there.
+    fn insert(&mut self, node: N, parent: Option<usize>) -> Option<usize> {
+        match self.indexes.entry(node.clone()) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                self.nodes.push((node, parent));
+                Some(*entry.insert(self.nodes.len() - 1))
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![];
+        loop {
+            let (node, parent) = &self.nodes[index];
+            path.push(node.clone());
+            match parent {
+                Some(parent) => index = *parent,
+                None => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth first search of the shortest path from any of the starts to a
+/// node satisfying `goal`, every step costing one.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue = starts
+        .into_iter()
+        .filter_map(|start| visited.insert(start, None))
+        .map(|index| (index, 0))
+        .collect::<VecDeque<_>>();
+
+    while let Some((index, cost)) = queue.pop_front() {
+        let node = visited.nodes[index].0.clone();
+        if goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: visited.path(index),
+            });
+        }
+        for next in successors(&node) {
+            if let Some(next) = visited.insert(next, Some(index)) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra search of the cheapest path from any of the starts to a node
+/// satisfying `goal`, `successors` yields the next nodes with the cost of
+/// reaching them.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), goal)
+}
+
+/// A* search, like [`dijkstra`] guided by a `heuristic` which must never
+/// overestimate the cost left to reach the goal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    let mut costs = vec![];
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = visited.insert(start, None) {
+            costs.push(C::default());
+            queue.push(Reverse((estimate, C::default(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // Stale entry of a node reached again more cheaply
+        if cost > costs[index] {
+            continue;
+        }
+        let node = visited.nodes[index].0.clone();
+        if goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: visited.path(index),
+            });
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            let next_index = match visited.indexes.get(&next) {
+                Some(&known) if next_cost >= costs[known] => continue,
+                Some(&known) => {
+                    visited.nodes[known].1 = Some(index);
+                    costs[known] = next_cost;
+                    known
+                }
+                None => {
+                    costs.push(next_cost);
+                    visited.insert(next, Some(index)).expect("the node is new")
+                }
+            };
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, Path};
+    use crate::{geometry::Position, grid::Grid};
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+....#..
+.#..#.#
+...#...
+";
+
+    fn open(grid: &Grid<char>, pos: Position) -> Vec<Position> {
+        grid.neighbours(pos)
+            .filter(|next| grid[*next] == '.')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let goal = Position::new(0, 6);
+
+        let path = bfs(
+            [Position::new(0, 0)],
+            |pos| open(&grid, *pos),
+            |pos| *pos == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.nodes.len(), 11);
+        assert_eq!(path.nodes.first(), Some(&Position::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|step| step[0].manhattan(&step[1]) == 1));
+
+        let nearest = bfs(
+            [Position::new(0, 0), Position::new(0, 3)],
+            |pos| open(&grid, *pos),
+            |pos| *pos == goal,
+        )
+        .unwrap();
+        assert_eq!(nearest.cost, 3);
+        assert_eq!(nearest.nodes[0], Position::new(0, 3));
+
+        let walled = bfs(
+            [Position::new(0, 0)],
+            |pos| open(&grid, *pos),
+            |pos| *pos == Position::new(0, 2),
+        );
+        assert_eq!(walled, None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // The direct edge is more expensive than the detour
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+
+        assert_eq!(
+            dijkstra(['a'], edges, |node| *node == 'd'),
+            Some(Path {
+                cost: 6,
+                nodes: vec!['a', 'b', 'c', 'd']
+            })
+        );
+        assert_eq!(dijkstra(['d'], edges, |node| *node == 'a'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let goal = Position::new(0, 6);
+
+        let path = astar(
+            [Position::new(0, 0)],
+            |pos| open(&grid, *pos).into_iter().map(|next| (next, 1)),
+            |pos| pos.manhattan(&goal),
+            |pos| *pos == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.nodes.len(), 11);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use crate::geometry::Point;
+
+/// Smallest rectangle containing a set of points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point<i64>,
+    pub max: Point<i64>,
+}
+
+impl Bounds {
+    pub fn new(point: Point<i64>) -> Self {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    pub fn contains(&self, point: Point<i64>) -> bool {
+        (self.min.row..=self.max.row).contains(&point.row)
+            && (self.min.col..=self.max.col).contains(&point.col)
+    }
+
+    /// Grows the bounds to contain `point`.
+    pub fn extend(&mut self, point: Point<i64>) {
+        self.min = Point::new(self.min.row.min(point.row), self.min.col.min(point.col));
+        self.max = Point::new(self.max.row.max(point.row), self.max.col.max(point.col));
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.row - self.min.row + 1
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.col - self.min.col + 1
+    }
+}
+
+/// Unbounded grid keyed by signed points, cells never set hold the default
+/// value.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    default: T,
+    cells: HashMap<Point<i64>, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            default,
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Cell at `point`, the default value when it was never set.
+    pub fn get(&self, point: Point<i64>) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, point: Point<i64>, value: T) {
+        self.track(point);
+        self.cells.insert(point, value);
+    }
+
+    fn track(&mut self, point: Point<i64>) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(point),
+            None => self.bounds = Some(Bounds::new(point)),
+        }
+    }
+
+    /// Bounds of the cells set so far, `None` while the grid is empty.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells set with their point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i64>, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// Displays the bounds of the grid drawing each cell with `renderer`,
+    /// one line per row.
+    pub fn display<R, F>(&self, renderer: F) -> SparseRender<'_, T, F>
+    where
+        F: Fn(&T) -> R,
+        R: Display,
+    {
+        SparseRender {
+            grid: self,
+            renderer,
+        }
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Mutable cell at `point`, set to the default value when missing.
+    pub fn entry(&mut self, point: Point<i64>) -> &mut T {
+        self.track(point);
+        let default = &self.default;
+        self.cells.entry(point).or_insert_with(|| default.clone())
+    }
+}
+
+/// [`SparseGrid`] cropped to its bounds, see [`SparseGrid::display`].
+pub struct SparseRender<'a, T, F> {
+    grid: &'a SparseGrid<T>,
+    renderer: F,
+}
+
+impl<'a, T, F, R> Display for SparseRender<'a, T, F>
+where
+    F: Fn(&T) -> R,
+    R: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(Bounds { min, max }) = self.grid.bounds {
+            for row in min.row..=max.row {
+                for col in min.col..=max.col {
+                    let cell = self.grid.get(Point::new(row, col));
+                    write!(f, "{}", (self.renderer)(cell))?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, SparseGrid};
+    use crate::geometry::Point;
+
+    #[test]
+    fn test_get_and_bounds() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.bounds(), None);
+
+        grid.set(Point::new(-2, 3), '#');
+        grid.set(Point::new(1, -1), '#');
+        *grid.entry(Point::new(0, 5)) = 'o';
+
+        assert_eq!(*grid.get(Point::new(-2, 3)), '#');
+        assert_eq!(*grid.get(Point::new(100, -100)), '.');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Point::new(-2, -1),
+                max: Point::new(1, 5)
+            })
+        );
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.height(), bounds.width()), (4, 7));
+        assert!(bounds.contains(Point::new(0, 0)));
+        assert!(!bounds.contains(Point::new(2, 0)));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(grid.display(|_| '#').to_string(), "");
+
+        grid.set(Point::new(-1, -1), true);
+        grid.set(Point::new(0, 1), true);
+
+        assert_eq!(
+            grid.display(|cell| if *cell { '#' } else { '.' })
+                .to_string(),
+            "#..\n..#\n"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    cell::Cell,
+    fmt::{Debug, Display},
+};
+
+/// Value returned by a part or an input generator.
+///
+/// `aoc_main` wraps the returned value and calls `(&&Returned::new(value)).value()`
+/// with [`ResultValue`], [`DisplayResultValue`] and [`PlainValue`] in scope:
+/// method resolution picks [`ResultValue`] for `Result`s whose error converts
+/// into `anyhow::Error`, then [`DisplayResultValue`] for the other errors that
+/// can be displayed, like `Box<dyn Error>`, and falls back to [`PlainValue`] for
+/// anything else.
+pub struct Returned<T>(Cell<Option<T>>);
+
+impl<T> Returned<T> {
+    pub fn new(value: T) -> Self {
+        Returned(Cell::new(Some(value)))
+    }
+
+    fn take(&self) -> T {
+        self.0.take().expect("Returned value already taken")
+    }
+}
+
+pub trait ResultValue {
+    type Value;
+
+    fn value(&self) -> anyhow::Result<Self::Value>;
+}
+
+impl<T, E> ResultValue for &Returned<Result<T, E>>
+where
+    E: Into<anyhow::Error>,
+{
+    type Value = T;
+
+    fn value(&self) -> anyhow::Result<Self::Value> {
+        self.take().map_err(Into::into)
+    }
+}
+
+pub trait DisplayResultValue {
+    type Value;
+
+    fn value(&self) -> anyhow::Result<Self::Value>;
+}
+
+/// Errors which are not `Send + Sync`, like `Box<dyn Error>`, keep only their
+/// message.
+impl<T, E> DisplayResultValue for &&Returned<Result<T, E>>
+where
+    E: Display,
+{
+    type Value = T;
+
+    fn value(&self) -> anyhow::Result<Self::Value> {
+        self.take().map_err(|err| anyhow::anyhow!("{}", err))
+    }
+}
+
+pub trait PlainValue {
+    type Value;
+
+    fn value(&self) -> anyhow::Result<Self::Value>;
+}
+
+impl<T> PlainValue for Returned<T> {
+    type Value = T;
+
+    fn value(&self) -> anyhow::Result<Self::Value> {
+        Ok(self.take())
+    }
+}
+
+/// Answer of a part, rendered with `Display` when the type implements it and
+/// with `Debug` otherwise. `debug` is what the `dayN` binaries log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: String,
+    pub debug: String,
+}
+
+/// Borrowed answer, rendered by calling `(&Rendered(&answer)).render()` with
+/// both [`DisplayAnswer`] and [`DebugAnswer`] in scope, like [`Returned`].
+pub struct Rendered<'a, T>(pub &'a T);
+
+pub trait DisplayAnswer {
+    fn render(&self) -> Answer;
+}
+
+impl<T: Display + Debug> DisplayAnswer for Rendered<'_, T> {
+    fn render(&self) -> Answer {
+        Answer {
+            value: self.0.to_string(),
+            debug: format!("{:?}", self.0),
+        }
+    }
+}
+
+pub trait DebugAnswer {
+    fn render(&self) -> Answer;
+}
+
+impl<T: Debug> DebugAnswer for &Rendered<'_, T> {
+    fn render(&self) -> Answer {
+        let debug = format!("{:?}", self.0);
+        Answer {
+            value: debug.clone(),
+            debug,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DebugAnswer, DisplayAnswer, DisplayResultValue, PlainValue, Rendered, ResultValue, Returned,
+    };
+
+    // The explicit borrows are what `aoc_main` generates to prefer `ResultValue`
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn test_plain_value() {
+        let value = (&&Returned::new(42)).value();
+
+        assert_eq!(value.unwrap(), 42);
+    }
+
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn test_result_value() {
+        let ok = (&&Returned::new(anyhow::Ok(42))).value();
+        let err = (&&Returned::new("x".parse::<u32>())).value();
+
+        assert_eq!(ok.unwrap(), 42);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+    }
+
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn test_display_result_value() {
+        let boxed: Result<u32, Box<dyn std::error::Error>> =
+            Err("x".parse::<u32>().unwrap_err().into());
+        let err = (&&Returned::new(boxed)).value();
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+
+        let ok: Result<u32, Box<dyn std::error::Error>> = Ok(42);
+        assert_eq!((&&Returned::new(ok)).value().unwrap(), 42);
+
+        let message: Result<u32, String> = Err("No answer".to_string());
+        let err = (&&Returned::new(message)).value();
+        assert_eq!(err.unwrap_err().to_string(), "No answer");
+    }
+
+    #[allow(clippy::needless_borrow)]
+    #[test]
+    fn test_rendered_answer() {
+        let text = (&Rendered(&"TPGVQPFDH".to_string())).render();
+        let list = (&Rendered(&vec![1, 2])).render();
+
+        assert_eq!(text.value, "TPGVQPFDH");
+        assert_eq!(text.debug, "\"TPGVQPFDH\"");
+        assert_eq!(list.value, "[1, 2]");
+    }
+}
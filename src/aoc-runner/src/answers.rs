@@ -0,0 +1,210 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use serde::{
+    de::{DeserializeOwned, Error as _},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::report::Report;
+
+/// Outcome of checking an answer against the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// Accepted answers of a year, stored in `answers.toml` as
+///
+/// ```toml
+/// [day5]
+/// part1 = "TPGVQPFDH"
+/// part2 = "DMRDFRHHH"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: ByPart<String>,
+}
+
+impl Answers {
+    pub fn path(base: impl AsRef<Path>) -> PathBuf {
+        base.as_ref().join("answers.toml")
+    }
+
+    /// Loads the answers file, a missing file has no answers.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Answers> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| content.parse())
+            .with_context(|| format!("Reading answers from {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Writing answers to {}", path.display()))
+    }
+
+    pub fn get(&self, day: u32, part: u8) -> Option<&str> {
+        self.days.get(day, part).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u32, part: u8, answer: impl Into<String>) {
+        *self.days.entry(day, part) = answer.into();
+    }
+
+    pub fn check(&self, day: u32, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Marks the successful parts of the report as correct, wrong or unknown.
+    pub fn verify(&self, report: &mut Report) {
+        for part in &mut report.parts {
+            if let Ok(answer) = &part.answer {
+                part.verdict = self.check(report.day, part.part, answer);
+            }
+        }
+    }
+
+    /// Takes the successful parts of the reports as the new accepted answers.
+    pub fn record(&mut self, reports: &[Report]) {
+        for report in reports {
+            for part in &report.parts {
+                if let Ok(answer) = &part.answer {
+                    self.set(report.day, part.part, answer.as_str());
+                }
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+/// Values keyed by day and part, stored as `[dayN]` tables of `partN` keys
+/// kept in numeric order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ByPart<T>(BTreeMap<u32, BTreeMap<u8, T>>);
+
+impl<T> Default for ByPart<T> {
+    fn default() -> Self {
+        ByPart(BTreeMap::new())
+    }
+}
+
+impl<T> ByPart<T> {
+    pub fn get(&self, day: u32, part: u8) -> Option<&T> {
+        self.0.get(&day)?.get(&part)
+    }
+}
+
+impl<T: Default> ByPart<T> {
+    pub fn entry(&mut self, day: u32, part: u8) -> &mut T {
+        self.0.entry(day).or_default().entry(part).or_default()
+    }
+}
+
+impl<T: Serialize> Serialize for ByPart<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (day, parts) in &self.0 {
+            let parts = parts
+                .iter()
+                .map(|(part, value)| (format!("part{}", part), value))
+                .collect::<BTreeMap<_, _>>();
+            map.serialize_entry(&format!("day{}", day), &parts)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for ByPart<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let days = HashMap::<String, HashMap<String, T>>::deserialize(deserializer)?;
+        let mut by_part = BTreeMap::<u32, BTreeMap<u8, T>>::new();
+
+        for (day, parts) in days {
+            let day = parse_key(&day, "day").map_err(D::Error::custom)?;
+            for (part, value) in parts {
+                let part = parse_key(&part, "part").map_err(D::Error::custom)?;
+                by_part.entry(day).or_default().insert(part, value);
+            }
+        }
+
+        Ok(ByPart(by_part))
+    }
+}
+
+fn parse_key<T: std::str::FromStr>(key: &str, prefix: &str) -> Result<T, String> {
+    key.strip_prefix(prefix)
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("invalid key {}, expected {}N", key, prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = "[day5]\npart1 = \"TPGVQPFDH\"\n".parse().unwrap();
+
+        assert_eq!(answers.check(5, 1, "TPGVQPFDH"), Verdict::Correct);
+        assert_eq!(
+            answers.check(5, 1, "DMRDFRHHH"),
+            Verdict::Wrong {
+                expected: "TPGVQPFDH".to_string()
+            }
+        );
+        assert_eq!(answers.check(5, 2, "DMRDFRHHH"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(10, 2, "CRT\n#..#");
+        answers.set(2, 1, "9241");
+        answers.set(10, 1, "15880");
+
+        let content = toml::to_string(&answers).unwrap();
+
+        assert_eq!(
+            content,
+            "[day2]\npart1 = \"9241\"\n\n[day10]\npart1 = \"15880\"\npart2 = \"CRT\\n#..#\"\n"
+        );
+        assert_eq!(content.parse::<Answers>().unwrap(), answers);
+        assert!("[dayfive]\npart1 = \"1\"\n".parse::<Answers>().is_err());
+    }
+}
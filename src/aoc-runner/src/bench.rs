@@ -0,0 +1,128 @@
+use std::{
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
+
+use crate::report::format_duration;
+
+/// Timing statistics of a part run many times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        // Both middle samples are the same one for an odd number of runs
+        let median = (sorted[(runs - 1) / 2] + sorted[runs / 2]) / 2;
+        let mean = sorted.iter().sum::<Duration>() / runs as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Some(Stats {
+            runs,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, mean {}, stddev {} over {} runs",
+            format_duration(self.min),
+            format_duration(self.median),
+            format_duration(self.mean),
+            format_duration(self.stddev),
+            self.runs
+        )
+    }
+}
+
+/// Runs `f` a tenth of `runs` times to warm up, then `runs` times measuring
+/// each execution. Stops at the first error.
+pub fn bench<T>(
+    runs: NonZeroUsize,
+    f: impl Fn() -> anyhow::Result<T>,
+) -> (anyhow::Result<T>, Duration, Option<Stats>) {
+    let runs = runs.get();
+    for _ in 0..runs.div_ceil(10) {
+        let now = Instant::now();
+        if let Err(err) = f() {
+            return (Err(err), now.elapsed(), None);
+        }
+    }
+
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let now = Instant::now();
+        let outcome = f();
+        let elapsed = now.elapsed();
+        if outcome.is_err() {
+            return (outcome, elapsed, None);
+        }
+        samples.push(elapsed);
+        result = Some(outcome);
+    }
+
+    let stats = Stats::from_samples(&samples).expect("runs is not zero");
+    (result.expect("runs is not zero"), stats.median, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, num::NonZeroUsize, time::Duration};
+
+    use super::{bench, Stats};
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_bench_runs() {
+        let calls = Cell::new(0);
+
+        let runs = NonZeroUsize::new(20).unwrap();
+        let (result, _, stats) = bench(runs, || {
+            calls.set(calls.get() + 1);
+            anyhow::Ok(calls.get())
+        });
+
+        assert_eq!(result.unwrap(), 22);
+        assert_eq!(stats.unwrap().runs, 20);
+
+        let (result, _, stats) = bench(runs, || -> anyhow::Result<()> { anyhow::bail!("boom") });
+        assert!(result.is_err());
+        assert!(stats.is_none());
+    }
+}
@@ -0,0 +1,252 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::input::default_path;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/wolf4ood/aoc";
+
+/// Sends the requests of the [`Client`], the stand-in server of the tests
+/// plugs in here.
+pub trait Transport {
+    /// Sends a GET request authenticated with the session cookie and returns
+    /// the body, failing on any status other than 200.
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String>;
+
+    /// Sends a POST request with an url encoded form, like [`Transport::get`].
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String>;
+}
+
+/// [`Transport`] over HTTP(S), backed by ureq.
+pub struct HttpTransport {
+    agent: ureq::Agent,
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        HttpTransport {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, session: &str) -> anyhow::Result<String> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        read_body("GET", url, response)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(form);
+        read_body("POST", url, response)
+    }
+}
+
+fn read_body(
+    method: &str,
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> anyhow::Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => Err(anyhow!(
+            "{} {} failed with status {}: {}",
+            method,
+            url,
+            status,
+            response.into_string().unwrap_or_default().trim()
+        )),
+        Err(err) => Err(err).with_context(|| format!("{} {}", method, url)),
+    }
+}
+
+/// Client of the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    transport: Box<dyn Transport>,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        Client {
+            base_url: BASE_URL.to_string(),
+            session: session.into(),
+            transport: Box::new(HttpTransport::default()),
+        }
+    }
+
+    /// Client authenticated with the session token from the configuration,
+    /// see [`session`].
+    pub fn from_config() -> anyhow::Result<Self> {
+        Ok(Client::new(session()?))
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Box::new(transport);
+        self
+    }
+
+    pub fn input(&self, year: u32, day: u32) -> anyhow::Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        self.transport
+            .get(&url, &self.session)
+            .with_context(|| format!("Fetching the input of {} day {}", year, day))
+    }
+
+    /// Posts the answer of a part, returning the html page of the response.
+    pub fn answer(&self, year: u32, day: u32, part: u8, answer: &str) -> anyhow::Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        self.transport
+            .post(
+                &url,
+                &self.session,
+                &[("level", &level), ("answer", answer)],
+            )
+            .with_context(|| format!("Submitting part {} of {} day {}", part, year, day))
+    }
+}
+
+/// Path of the session token file, `~/.config/aoc/session` unless
+/// `XDG_CONFIG_HOME` is set.
+pub fn session_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .map(|config| config.join("aoc").join("session"))
+}
+
+/// Session token of adventofcode.com, read from the `AOC_SESSION` environment
+/// variable or from the [`session_path`] file.
+pub fn session() -> anyhow::Result<String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = session_path().ok_or_else(|| anyhow!("Cannot locate the config directory"))?;
+    let session = std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Reading the session token from {}, set AOC_SESSION or store the \
+             `session` cookie of adventofcode.com there",
+            path.display()
+        )
+    })?;
+
+    match session.trim() {
+        "" => bail!("The session token in {} is empty", path.display()),
+        session => Ok(session.to_string()),
+    }
+}
+
+/// Whether the input is already there, an empty placeholder is not.
+fn is_cached(path: &Path) -> bool {
+    path.metadata().is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Returns the path of the input of the day under `base`, downloading it
+/// first unless it is already cached there.
+pub fn cached_input(
+    client: &Client,
+    base: impl AsRef<Path>,
+    year: u32,
+    day: u32,
+) -> anyhow::Result<PathBuf> {
+    let path = default_path(base, day);
+    if is_cached(&path) {
+        return Ok(path);
+    }
+
+    let input = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Creating the input directory {}", dir.display()))?;
+    }
+    std::fs::write(&path, input)
+        .with_context(|| format!("Writing the input to {}", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::stub::serve;
+
+    use super::{cached_input, Client, Transport};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Rc<RefCell<Vec<String>>>);
+
+    impl Transport for Recorder {
+        fn get(&self, url: &str, _session: &str) -> anyhow::Result<String> {
+            self.0.borrow_mut().push(url.to_string());
+            Ok("1\n2\n".to_string())
+        }
+
+        fn post(
+            &self,
+            url: &str,
+            _session: &str,
+            _form: &[(&str, &str)],
+        ) -> anyhow::Result<String> {
+            anyhow::bail!("Unexpected POST {}", url)
+        }
+    }
+
+    #[test]
+    fn test_fetch_from_server() {
+        let (url, server) = serve(vec![(200, "1000\n2000\n"), (400, "Puzzle inputs differ")]);
+        let client = Client::new("cafe").with_base_url(url);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        let err = client.input(2022, 26).unwrap_err();
+        assert!(format!("{:?}", err).contains("status 400: Puzzle inputs differ"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "GET /2022/day/1/input HTTP/1.1");
+        assert_eq!(requests[0].header("cookie"), Some("session=cafe"));
+        assert_eq!(
+            requests[0].header("user-agent"),
+            Some("github.com/wolf4ood/aoc")
+        );
+    }
+
+    #[test]
+    fn test_cached_input() {
+        let base = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let recorder = Recorder::default();
+        let client = Client::new("cafe")
+            .with_base_url("https://aoc.test")
+            .with_transport(recorder.clone());
+
+        let first = cached_input(&client, &base, 2022, 9).unwrap();
+        let second = cached_input(&client, &base, 2022, 9).unwrap();
+        let input = std::fs::read_to_string(&first).unwrap();
+        std::fs::remove_dir_all(&base).unwrap();
+
+        assert_eq!(first, base.join("input").join("day9.txt"));
+        assert_eq!(first, second);
+        assert_eq!(input, "1\n2\n");
+        assert_eq!(
+            *recorder.0.borrow(),
+            vec!["https://aoc.test/2022/day/9/input"]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+/// Calling convention for the input generators wired by `aoc_main`.
+///
+/// The marker `M` tells apart the supported signatures, so both
+/// `fn generator(input: &str) -> T` and
+/// `fn generator(input: &str, strict: bool) -> T` can be passed to [`call`],
+/// the latter getting the `--strict` flag of the run.
+pub trait Generator<'a, M> {
+    type Output;
+
+    fn call(&self, input: &'a str, strict: bool) -> Self::Output;
+}
+
+pub struct InputOnly;
+pub struct WithStrict;
+
+impl<'a, F, T> Generator<'a, InputOnly> for F
+where
+    F: Fn(&'a str) -> T,
+{
+    type Output = T;
+
+    fn call(&self, input: &'a str, _strict: bool) -> Self::Output {
+        self(input)
+    }
+}
+
+impl<'a, F, T> Generator<'a, WithStrict> for F
+where
+    F: Fn(&'a str, bool) -> T,
+{
+    type Output = T;
+
+    fn call(&self, input: &'a str, strict: bool) -> Self::Output {
+        self(input, strict)
+    }
+}
+
+pub fn call<'a, M, G>(generator: &G, input: &'a str, strict: bool) -> G::Output
+where
+    G: Generator<'a, M>,
+{
+    generator.call(input, strict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::call;
+
+    fn words(input: &str) -> Vec<&str> {
+        input.split(' ').collect()
+    }
+
+    fn numbers(input: &str, strict: bool) -> Result<Vec<u32>, String> {
+        let numbers = input.split(' ').map(str::parse);
+        if strict {
+            numbers
+                .collect::<Result<_, _>>()
+                .map_err(|err| format!("{}", err))
+        } else {
+            Ok(numbers.flatten().collect())
+        }
+    }
+
+    #[test]
+    fn test_call_signatures() {
+        assert_eq!(call(&words, "a b", true), vec!["a", "b"]);
+        assert_eq!(call(&numbers, "1 x 3", false), Ok(vec![1, 3]));
+        assert!(call(&numbers, "1 x 3", true).is_err());
+    }
+}
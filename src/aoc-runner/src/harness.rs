@@ -0,0 +1,145 @@
+use std::{
+    num::NonZeroUsize,
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::Instant,
+};
+
+use anyhow::anyhow;
+
+use crate::{
+    answer::Answer,
+    answers::Verdict,
+    bench::bench,
+    report::{format_duration, PartReport, Report},
+};
+
+/// How a [`Harness`] runs a day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Config {
+    /// Logs each step as it runs.
+    pub verbose: bool,
+    /// Runs each part that many times after a warm up.
+    pub bench: Option<NonZeroUsize>,
+    /// Given to the input generator, to fail on any line it cannot parse.
+    pub strict: bool,
+}
+
+/// Times the input generator and the parts of a day, collecting a [`Report`].
+pub struct Harness {
+    report: Report,
+    config: Config,
+}
+
+impl Harness {
+    pub fn new(year: u32, day: u32, config: Config) -> Self {
+        Harness {
+            report: Report::new(year, day),
+            config,
+        }
+    }
+
+    /// Runs the input generator with the `strict` flag of the run, returns
+    /// `None` when it fails.
+    pub fn generator<T>(&mut self, generator: impl FnOnce(bool) -> anyhow::Result<T>) -> Option<T> {
+        let strict = self.config.strict;
+        let now = Instant::now();
+        let result = catch(|| generator(strict));
+        let elapsed = now.elapsed();
+
+        match result {
+            Ok(parsed) => {
+                if self.config.verbose {
+                    println!(
+                        "=> Input generator execution time: {}",
+                        format_duration(elapsed)
+                    );
+                }
+                self.report.parse = Some(elapsed);
+                Some(parsed)
+            }
+            Err(err) => {
+                if self.config.verbose {
+                    eprintln!(
+                        "=> Input generator failed after {}: {:?}",
+                        format_duration(elapsed),
+                        err
+                    );
+                }
+                self.report.error = Some(format!("{:?}", err));
+                None
+            }
+        }
+    }
+
+    pub fn part(&mut self, part: u8, solve: impl Fn() -> anyhow::Result<Answer>) {
+        let solve = || catch(&solve);
+        if self.config.verbose {
+            println!(
+                "Running AoC {} day {}, part {}",
+                self.report.year, self.report.day, part
+            );
+        }
+
+        let (result, time, stats) = match self.config.bench {
+            Some(runs) => bench(runs, solve),
+            None => {
+                let now = Instant::now();
+                let result = solve();
+                (result, now.elapsed(), None)
+            }
+        };
+
+        let answer = match result {
+            Ok(answer) => {
+                if self.config.verbose {
+                    println!(
+                        "=> Part {} execution time: {} with result : {}",
+                        part,
+                        format_duration(time),
+                        answer.debug
+                    );
+                    if let Some(stats) = stats {
+                        println!("=> Part {} benchmark: {}", part, stats);
+                    }
+                }
+                Ok(answer.value)
+            }
+            Err(err) => {
+                if self.config.verbose {
+                    eprintln!(
+                        "=> Part {} failed after {}: {:?}",
+                        part,
+                        format_duration(time),
+                        err
+                    );
+                }
+                Err(format!("{:?}", err))
+            }
+        };
+
+        self.report.parts.push(PartReport {
+            part,
+            answer,
+            time,
+            verdict: Verdict::Unknown,
+            stats,
+        });
+    }
+
+    pub fn finish(self) -> Report {
+        self.report
+    }
+}
+
+/// Turns a panic into an error, so that a panicking part does not abort the
+/// other parts, days or inputs of a run.
+fn catch<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(anyhow!("Panicked: {}", message))
+    })
+}
@@ -0,0 +1,140 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+pub struct InputOpts {
+    /// Puzzle input files or directories of inputs, use `-` to read from stdin
+    /// [default: input/dayN.txt]
+    #[structopt(parse(from_os_str))]
+    pub inputs: Vec<PathBuf>,
+}
+
+impl InputOpts {
+    /// Resolves the inputs given on the command line, expanding directories
+    /// to the files they contain sorted by name.
+    pub fn sources(&self, base: impl AsRef<Path>, day: u32) -> anyhow::Result<Vec<Source>> {
+        if self.inputs.is_empty() {
+            return Ok(vec![Source::resolve(None, base, day)]);
+        }
+
+        let mut sources = vec![];
+        for input in &self.inputs {
+            if input.is_dir() {
+                let mut files = std::fs::read_dir(input)
+                    .and_then(|entries| {
+                        entries
+                            .map(|entry| entry.map(|entry| entry.path()))
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .with_context(|| format!("Listing inputs in {}", input.display()))?;
+                files.retain(|path| path.is_file());
+                files.sort();
+                sources.extend(files.into_iter().map(Source::File));
+            } else {
+                sources.push(Source::resolve(Some(input.clone()), &base, day));
+            }
+        }
+
+        Ok(sources)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Source {
+    pub fn resolve(input: Option<PathBuf>, base: impl AsRef<Path>, day: u32) -> Source {
+        match input {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::File(path),
+            None => Source::File(default_path(base, day)),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Source::Stdin => "stdin".to_string(),
+            Source::File(path) => path.display().to_string(),
+        }
+    }
+
+    pub fn read(&self) -> anyhow::Result<String> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Reading input from stdin")?;
+                Ok(input)
+            }
+            Source::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Reading input from {}", path.display())),
+        }
+    }
+}
+
+pub fn default_path(base: impl AsRef<Path>, day: u32) -> PathBuf {
+    base.as_ref().join("input").join(format!("day{}.txt", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{default_path, InputOpts, Source};
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(
+            Source::resolve(None, "/aoc/aoc2022", 9),
+            Source::File(PathBuf::from("/aoc/aoc2022/input/day9.txt"))
+        );
+        assert_eq!(
+            default_path("aoc2020", 1),
+            PathBuf::from("aoc2020/input/day1.txt")
+        );
+    }
+
+    #[test]
+    fn test_explicit_sources() {
+        assert_eq!(
+            Source::resolve(Some(PathBuf::from("-")), "/aoc", 9),
+            Source::Stdin
+        );
+        assert_eq!(
+            Source::resolve(Some(PathBuf::from("mine.txt")), "/aoc", 9),
+            Source::File(PathBuf::from("mine.txt"))
+        );
+    }
+
+    #[test]
+    fn test_directory_sources() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("mine.txt"), "1").unwrap();
+        std::fs::write(dir.join("alice.txt"), "2").unwrap();
+
+        let opts = InputOpts {
+            inputs: vec![PathBuf::from("sample12.txt"), dir.clone()],
+        };
+        let sources = opts.sources("/aoc", 12).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            sources,
+            vec![
+                Source::File(PathBuf::from("sample12.txt")),
+                Source::File(dir.join("alice.txt")),
+                Source::File(dir.join("mine.txt")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+pub use anyhow;
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod fetch;
+pub mod generator;
+pub mod harness;
+pub mod input;
+pub mod part;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+mod stub;
+pub mod submit;
+
+pub use harness::Harness;
+pub use registry::Registry;
+pub use solution::Solution;
+
+/// Turns the value returned by a part or a generator into an `anyhow::Result`,
+/// see [`answer::Returned`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! returned {
+    ($value:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::answer::{DisplayResultValue as _, PlainValue as _, ResultValue as _};
+        (&&$crate::answer::Returned::new($value)).value()
+    }};
+}
+
+/// Day of a `dayN` module path, which `aoc_main` checks against its `day` at
+/// compile time.
+#[doc(hidden)]
+pub const fn module_day(module_path: &str) -> Option<u32> {
+    let path = module_path.as_bytes();
+    let mut start = path.len();
+    while start > 0 && path[start - 1] != b':' {
+        start -= 1;
+    }
+    if path.len() < start + 4
+        || path[start] != b'd'
+        || path[start + 1] != b'a'
+        || path[start + 2] != b'y'
+    {
+        return None;
+    }
+
+    let mut day = 0;
+    let mut idx = start + 3;
+    while idx < path.len() {
+        if !path[idx].is_ascii_digit() || day > 25 {
+            return None;
+        }
+        day = day * 10 + (path[idx] - b'0') as u32;
+        idx += 1;
+    }
+    Some(day)
+}
+
+/// Renders the answer of a part, see [`answer::Rendered`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! rendered {
+    ($value:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::answer::{DebugAnswer as _, DisplayAnswer as _};
+        (&$crate::answer::Rendered(&$value)).render()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::module_day;
+
+    #[test]
+    fn test_module_day() {
+        assert_eq!(module_day("aoc2022::day15"), Some(15));
+        assert_eq!(module_day("day7"), Some(7));
+        assert_eq!(module_day("aoc2022::day15::tests"), None);
+        assert_eq!(module_day("aoc2022::days"), None);
+        assert_eq!(module_day("aoc2022::day"), None);
+        assert_eq!(module_day("aoc2022::day123456789012"), None);
+    }
+}
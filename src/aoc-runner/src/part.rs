@@ -0,0 +1,63 @@
+/// Calling convention for the part functions wired by `aoc_main`.
+///
+/// The marker `M` tells apart the supported signatures, so both
+/// `fn part1() -> T` and `fn part1(input: &I) -> T` can be passed to [`call`].
+pub trait Part<I: ?Sized, M> {
+    type Output;
+
+    fn call(&self, input: &I) -> Self::Output;
+}
+
+pub struct NoInput;
+pub struct WithInput;
+
+impl<F, I, T> Part<I, NoInput> for F
+where
+    F: Fn() -> T,
+    I: ?Sized,
+{
+    type Output = T;
+
+    fn call(&self, _input: &I) -> Self::Output {
+        self()
+    }
+}
+
+impl<F, I, T> Part<I, WithInput> for F
+where
+    F: Fn(&I) -> T,
+    I: ?Sized,
+{
+    type Output = T;
+
+    fn call(&self, input: &I) -> Self::Output {
+        self(input)
+    }
+}
+
+pub fn call<I, M, P>(part: &P, input: &I) -> P::Output
+where
+    I: ?Sized,
+    P: Part<I, M>,
+{
+    part.call(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::call;
+
+    fn no_input() -> usize {
+        42
+    }
+
+    fn with_input(input: &str) -> usize {
+        input.len()
+    }
+
+    #[test]
+    fn test_call_signatures() {
+        assert_eq!(call(&no_input, "ignored"), 42);
+        assert_eq!(call(&with_input, "abc"), 3);
+    }
+}
@@ -0,0 +1,288 @@
+use std::{num::NonZeroUsize, path::PathBuf};
+
+use structopt::StructOpt;
+
+use crate::{
+    answers::Answers,
+    fetch::{self, Client},
+    harness::Config,
+    input,
+    report::{Format, Report},
+    scaffold,
+    solution::Solution,
+    submit::{self, Outcome},
+};
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    /// Runs the solutions against their input/dayN.txt, checking the answers
+    /// against answers.toml
+    Run(RunOpts),
+    /// Downloads the input of a day into input/dayN.txt, unless it is already
+    /// there
+    Fetch {
+        /// Day to fetch
+        #[structopt(short, long)]
+        day: u32,
+    },
+    /// Submits the answer of a part, unless it is known to be wrong from the
+    /// previous submissions in submissions.toml
+    Submit {
+        /// Day to submit
+        #[structopt(short, long)]
+        day: u32,
+        /// Part to submit
+        #[structopt(short, long, possible_values = &["1", "2"])]
+        part: u8,
+        /// Answer to submit [default: the answer of the solution]
+        answer: Option<String>,
+    },
+    /// Creates the module, binary, sample and input placeholder of a new day
+    New {
+        /// Year of the day [default: the year of the runner]
+        #[structopt(short, long)]
+        year: Option<u32>,
+        /// Day to create
+        #[structopt(short, long)]
+        day: u32,
+    },
+}
+
+#[derive(Debug, StructOpt)]
+pub struct RunOpts {
+    /// Day to run
+    #[structopt(short, long, required_unless = "all")]
+    pub day: Option<u32>,
+    /// Runs every registered day
+    #[structopt(short, long, conflicts_with = "day")]
+    pub all: bool,
+    /// Output format: text, json or csv
+    #[structopt(short, long, default_value = "text")]
+    pub format: Format,
+    /// Records the answers as the accepted ones in answers.toml
+    #[structopt(long)]
+    pub record: bool,
+    /// Runs each part N times after a warm up and reports the timings
+    #[structopt(long, value_name = "N")]
+    pub bench: Option<NonZeroUsize>,
+    /// Fails the input generator on any line it cannot parse, instead of
+    /// skipping it
+    #[structopt(long)]
+    pub strict: bool,
+    /// Downloads the missing inputs first, with the configured session token
+    #[structopt(long)]
+    pub fetch: bool,
+}
+
+/// All the solutions of a year, backing the year runner binary.
+pub struct Registry {
+    year: u32,
+    base: PathBuf,
+    solutions: Vec<Solution>,
+}
+
+impl Registry {
+    /// Registers the solutions of `year`, failing when a day has several of
+    /// them.
+    pub fn new(year: u32, base: &str, mut solutions: Vec<Solution>) -> anyhow::Result<Self> {
+        solutions.sort_by_key(|solution| solution.day);
+        if let Some(pair) = solutions.windows(2).find(|pair| pair[0].day == pair[1].day) {
+            anyhow::bail!("Several solutions for {} day {}", year, pair[0].day);
+        }
+        Ok(Registry {
+            year,
+            base: PathBuf::from(base),
+            solutions,
+        })
+    }
+
+    pub fn get(&self, day: u32) -> Option<&Solution> {
+        self.solutions.iter().find(|solution| solution.day == day)
+    }
+
+    pub fn solutions(&self) -> &[Solution] {
+        &self.solutions
+    }
+
+    /// Runs a solution against its default input, downloading it first with
+    /// `client` when it is missing.
+    pub fn run(&self, solution: &Solution, config: Config, client: Option<&Client>) -> Report {
+        if let Some(client) = client {
+            if let Err(err) = fetch::cached_input(client, &self.base, self.year, solution.day) {
+                let mut report = Report::new(solution.year, solution.day);
+                report.error = Some(format!("{:?}", err));
+                return report;
+            }
+        }
+        solution.run_source(
+            &input::Source::resolve(None, &self.base, solution.day),
+            config,
+        )
+    }
+
+    /// Entry point of the year runner binary.
+    pub fn main(&self) {
+        let command = Command::from_args();
+
+        let result = match command {
+            Command::Run(opts) => self.run_days(&opts),
+            Command::Fetch { day } => self.fetch(day),
+            Command::Submit { day, part, answer } => self.submit(day, part, answer),
+            Command::New { year, day } => self.new_day(year.unwrap_or(self.year), day),
+        };
+
+        match result {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(err) => {
+                eprintln!("Error: {:?}", err);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn fetch(&self, day: u32) -> anyhow::Result<bool> {
+        let path = fetch::cached_input(&Client::from_config()?, &self.base, self.year, day)?;
+        println!("Input of {} day {} in {}", self.year, day, path.display());
+        Ok(true)
+    }
+
+    fn new_day(&self, year: u32, day: u32) -> anyhow::Result<bool> {
+        if !(1..=25).contains(&day) {
+            anyhow::bail!("Invalid day {}, expected a value in 1..=25", day);
+        }
+        // The crates of the other years live next to this one as aocYYYY
+        let crate_dir = match self.base.parent() {
+            Some(parent) if year != self.year => parent.join(format!("aoc{}", year)),
+            _ => self.base.clone(),
+        };
+        if !crate_dir.is_dir() {
+            anyhow::bail!("No crate for {} in {}", year, crate_dir.display());
+        }
+
+        for path in scaffold::new_day(&crate_dir, year, day)? {
+            println!("Created {}", path.display());
+        }
+        Ok(true)
+    }
+
+    fn submit(&self, day: u32, part: u8, answer: Option<String>) -> anyhow::Result<bool> {
+        let client = Client::from_config()?;
+        let answer = match answer {
+            Some(answer) => answer,
+            None => self.answer(day, part, &client)?,
+        };
+
+        println!(
+            "Submitting {} for part {} of {} day {}",
+            answer, part, self.year, day
+        );
+        let response = submit::submit(&client, &self.base, self.year, day, part, &answer)?;
+        println!("{}", response.message);
+
+        Ok(response.outcome == Outcome::Correct)
+    }
+
+    /// Runs the solution of the day to get the answer of a part.
+    fn answer(&self, day: u32, part: u8, client: &Client) -> anyhow::Result<String> {
+        let solution = self
+            .get(day)
+            .ok_or_else(|| anyhow::anyhow!("No solution for {} day {}", self.year, day))?;
+        self.run(solution, Config::default(), Some(client))
+            .answer(part)
+            .map_err(|error| anyhow::anyhow!("{} day {}: {}", self.year, day, error))
+    }
+
+    fn run_days(&self, opts: &RunOpts) -> anyhow::Result<bool> {
+        let config = Config {
+            verbose: false,
+            bench: opts.bench,
+            strict: opts.strict,
+        };
+        let client = if opts.fetch {
+            Some(Client::from_config()?)
+        } else {
+            None
+        };
+        let days = match opts.day {
+            Some(day) => vec![day],
+            None => self.solutions.iter().map(|s| s.day).collect(),
+        };
+
+        let mut reports = days
+            .iter()
+            .map(|day| {
+                self.get(*day)
+                    .map(|solution| self.run(solution, config, client.as_ref()))
+                    .ok_or_else(|| anyhow::anyhow!("No solution for {} day {}", self.year, day))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let path = Answers::path(&self.base);
+        let mut answers = Answers::load(&path)?;
+        if opts.record {
+            answers.record(&reports);
+            answers.save(&path)?;
+            eprintln!("Recorded the answers in {}", path.display());
+        }
+        for report in &mut reports {
+            answers.verify(report);
+        }
+
+        if opts.format == Format::Text {
+            println!("AoC {}\n", self.year);
+        }
+        print!("{}", opts.format.render(&reports)?);
+
+        Ok(!reports.iter().any(Report::failed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        harness::{Config, Harness},
+        solution::Solution,
+    };
+
+    use super::Registry;
+
+    fn day(input: &str, harness: &mut Harness) {
+        harness.part(1, || Ok(crate::rendered!(input.len())));
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = Registry::new(
+            2022,
+            "/nowhere",
+            vec![Solution::new(2022, 9, day), Solution::new(2022, 1, day)],
+        )
+        .unwrap();
+
+        let days = registry
+            .solutions()
+            .iter()
+            .map(|s| s.day)
+            .collect::<Vec<_>>();
+        assert_eq!(days, vec![1, 9]);
+        assert!(registry.get(2).is_none());
+
+        let report = registry.run(registry.get(9).unwrap(), Config::default(), None);
+        assert!(report.failed());
+        assert!(report.error.unwrap().contains("/nowhere/input"));
+
+        let report = registry.get(1).unwrap().run("abc", Config::default());
+        assert_eq!(report.parts[0].answer, Ok("3".to_string()));
+
+        let duplicate = Registry::new(
+            2022,
+            "/nowhere",
+            vec![Solution::new(2022, 9, day), Solution::new(2022, 9, day)],
+        );
+        assert_eq!(
+            duplicate.err().unwrap().to_string(),
+            "Several solutions for 2022 day 9"
+        );
+    }
+}
@@ -0,0 +1,429 @@
+use std::{str::FromStr, time::Duration};
+
+use serde::Serialize;
+
+use crate::{answers::Verdict, bench::Stats};
+
+/// Outcome of running a single day: parse time and the answer of each part.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Option<Duration>,
+    pub error: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<String, String>,
+    pub time: Duration,
+    pub verdict: Verdict,
+    pub stats: Option<Stats>,
+}
+
+impl Report {
+    pub fn new(year: u32, day: u32) -> Self {
+        Report {
+            year,
+            day,
+            parse: None,
+            error: None,
+            parts: vec![],
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+            || self
+                .parts
+                .iter()
+                .any(|part| part.answer.is_err() || matches!(part.verdict, Verdict::Wrong { .. }))
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    /// Answer of a part, or why there is none.
+    pub fn answer(&self, part: u8) -> Result<String, String> {
+        if let Some(error) = &self.error {
+            return Err(format!("Input generator failed: {}", error));
+        }
+        match self.parts.iter().find(|report| report.part == part) {
+            Some(report) => report.answer.clone(),
+            None => Err(format!("No part {}", part)),
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration.as_nanos() < 1000 {
+        format!("{}ns", duration.as_nanos())
+    } else if duration.as_micros() < 1000 {
+        format!("{}µs", duration.as_micros())
+    } else if duration.as_millis() < 1000 {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{}s", duration.as_secs())
+    }
+}
+
+/// Renders the reports as a table with one row per part, followed by the
+/// answers spanning multiple lines, the errors and the benchmarks.
+pub fn summary(reports: &[Report]) -> String {
+    let reports = reports
+        .iter()
+        .map(|report| (report.day.to_string(), report))
+        .collect::<Vec<_>>();
+    table("Day", &reports, true)
+}
+
+/// Renders the reports of a day run against several inputs like [`summary`],
+/// with one row per input and part and without checking the answers.
+pub fn batch_summary(reports: &[(String, Report)]) -> String {
+    let reports = reports
+        .iter()
+        .map(|(input, report)| (input.clone(), report))
+        .collect::<Vec<_>>();
+    table("Input", &reports, false)
+}
+
+fn table(heading: &str, reports: &[(String, &Report)], checked: bool) -> String {
+    let status = if checked { "Status" } else { "" };
+    let mut rows = vec![[heading, "Part", "Answer", "Time", status].map(String::from)];
+    let mut details = vec![];
+
+    for (label, report) in reports {
+        if let Some(parse) = report.parse {
+            rows.push([
+                label.clone(),
+                "parse".to_string(),
+                String::new(),
+                format_duration(parse),
+                String::new(),
+            ]);
+        }
+        if let Some(error) = &report.error {
+            rows.push([
+                label.clone(),
+                "parse".to_string(),
+                "FAILED".to_string(),
+                String::new(),
+                String::new(),
+            ]);
+            details.push(format!(
+                "{} {} input generator failed: {}",
+                heading, label, error
+            ));
+        }
+        for part in &report.parts {
+            let answer = match &part.answer {
+                Ok(answer) if answer.contains('\n') => {
+                    details.push(format!(
+                        "{} {} part {}:\n{}",
+                        heading, label, part.part, answer
+                    ));
+                    "(see below)".to_string()
+                }
+                Ok(answer) => answer.clone(),
+                Err(error) => {
+                    details.push(format!(
+                        "{} {} part {} failed: {}",
+                        heading, label, part.part, error
+                    ));
+                    "FAILED".to_string()
+                }
+            };
+            let status = match (&part.answer, &part.verdict) {
+                _ if !checked => String::new(),
+                (Err(_), _) => String::new(),
+                (Ok(_), Verdict::Wrong { expected }) => {
+                    details.push(format!(
+                        "{} {} part {} is wrong, expected: {}",
+                        heading, label, part.part, expected
+                    ));
+                    "WRONG".to_string()
+                }
+                (Ok(_), verdict) => verdict.name().to_string(),
+            };
+            if let Some(stats) = part.stats {
+                details.push(format!(
+                    "{} {} part {}: {}",
+                    heading, label, part.part, stats
+                ));
+            }
+            rows.push([
+                label.clone(),
+                part.part.to_string(),
+                answer,
+                format_duration(part.time),
+                status,
+            ]);
+        }
+    }
+
+    let mut widths = [0; 5];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for (idx, row) in rows.iter().enumerate() {
+        let filled = row.len() - row.iter().rev().take_while(|cell| cell.is_empty()).count();
+        let line = row[..filled]
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        out.push_str(line.trim_end());
+        out.push('\n');
+        if idx == 0 {
+            let rule = widths
+                .iter()
+                .filter(|width| **width > 0)
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("-+-");
+            out.push_str(&rule);
+            out.push('\n');
+        }
+    }
+
+    let total = reports
+        .iter()
+        .map(|(_, report)| report.total_time())
+        .sum::<Duration>();
+    out.push_str(&format!("\nTotal time: {}\n", format_duration(total)));
+
+    for detail in details {
+        out.push('\n');
+        out.push_str(&detail);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Output format of the year runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!(
+                "Unknown format {}, expected text, json or csv",
+                other
+            )),
+        }
+    }
+}
+
+impl Format {
+    pub fn render(&self, reports: &[Report]) -> anyhow::Result<String> {
+        match self {
+            Format::Text => Ok(summary(reports)),
+            Format::Json => Ok(serde_json::to_string_pretty(&records(reports))? + "\n"),
+            Format::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                for record in records(reports) {
+                    writer.serialize(record)?;
+                }
+                Ok(String::from_utf8(writer.into_inner()?)?)
+            }
+        }
+    }
+}
+
+/// Flat view of a [`Report`], one per part, used by the json and csv formats.
+///
+/// A failing input generator yields a single record without `part`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_time_us: Option<u128>,
+    pub solve_time_us: Option<u128>,
+    pub status: Option<&'static str>,
+}
+
+pub fn records(reports: &[Report]) -> Vec<Record> {
+    let mut records = vec![];
+
+    for report in reports {
+        let parse_time_us = report.parse.map(|parse| parse.as_micros());
+        if let Some(error) = &report.error {
+            records.push(Record {
+                year: report.year,
+                day: report.day,
+                part: None,
+                answer: None,
+                error: Some(error.clone()),
+                parse_time_us,
+                solve_time_us: None,
+                status: None,
+            });
+        }
+        for part in &report.parts {
+            records.push(Record {
+                year: report.year,
+                day: report.day,
+                part: Some(part.part),
+                answer: part.answer.clone().ok(),
+                error: part.answer.clone().err(),
+                parse_time_us,
+                solve_time_us: Some(part.time.as_micros()),
+                status: part.answer.is_ok().then(|| part.verdict.name()),
+            });
+        }
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::answers::Verdict;
+
+    use super::{batch_summary, summary, Format, PartReport, Report};
+
+    fn report() -> Report {
+        Report {
+            year: 2022,
+            day: 10,
+            parse: None,
+            error: None,
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: Ok("13140".to_string()),
+                    time: Duration::from_micros(12),
+                    verdict: Verdict::Correct,
+                    stats: None,
+                },
+                PartReport {
+                    part: 2,
+                    answer: Err("Failed to parse".to_string()),
+                    time: Duration::from_millis(3),
+                    verdict: Verdict::Unknown,
+                    stats: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let report = report();
+        let mut wrong = report.clone();
+        wrong.day = 11;
+        wrong.parts.truncate(1);
+        wrong.parts[0].verdict = Verdict::Wrong {
+            expected: "10605".to_string(),
+        };
+
+        assert!(report.failed());
+        assert!(wrong.failed());
+        assert_eq!(
+            summary(&[report, wrong]),
+            "Day | Part | Answer | Time | Status
+----+------+--------+------+--------
+10  | 1    | 13140  | 12µs | correct
+10  | 2    | FAILED | 3ms
+11  | 1    | 13140  | 12µs | WRONG
+
+Total time: 3ms
+
+Day 10 part 2 failed: Failed to parse
+
+Day 11 part 1 is wrong, expected: 10605
+"
+        );
+    }
+
+    #[test]
+    fn test_batch_summary() {
+        let mut missing = report();
+        missing.error = Some("Reading input from day10.txt".to_string());
+        missing.parts.clear();
+
+        assert_eq!(
+            batch_summary(&[
+                ("sample10.txt".to_string(), report()),
+                ("day10.txt".to_string(), missing)
+            ]),
+            "Input        | Part  | Answer | Time
+-------------+-------+--------+-----
+sample10.txt | 1     | 13140  | 12µs
+sample10.txt | 2     | FAILED | 3ms
+day10.txt    | parse | FAILED
+
+Total time: 3ms
+
+Input sample10.txt part 2 failed: Failed to parse
+
+Input day10.txt input generator failed: Reading input from day10.txt
+"
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut parsed = report();
+        parsed.day = 11;
+        parsed.parse = Some(Duration::from_micros(40));
+        parsed.parts.truncate(1);
+
+        assert_eq!(
+            Format::Csv.render(&[report(), parsed]).unwrap(),
+            "year,day,part,answer,error,parse_time_us,solve_time_us,status
+2022,10,1,13140,,,12,correct
+2022,10,2,,Failed to parse,,3000,
+2022,11,1,13140,,40,12,correct
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let mut failed = report();
+        failed.error = Some("Reading input".to_string());
+        failed.parts.clear();
+
+        assert_eq!(
+            Format::Json.render(&[failed]).unwrap(),
+            r#"[
+  {
+    "year": 2022,
+    "day": 10,
+    "part": null,
+    "answer": null,
+    "error": "Reading input",
+    "parse_time_us": null,
+    "solve_time_us": null,
+    "status": null
+  }
+]
+"#
+        );
+    }
+}
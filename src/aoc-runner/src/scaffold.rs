@@ -0,0 +1,220 @@
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+use crate::input::default_path;
+
+/// Source of a new `dayN` module, its parts count the lines of the input
+/// until they are implemented. The sample arguments are left commented out
+/// until the expected answers are known.
+pub fn template(year: u32, day: u32) -> String {
+    format!(
+        r#"use aoc_attributes::aoc_main;
+
+#[aoc_main(
+    year = {year},
+    day = {day},
+    part1 = "part1",
+    part2 = "part2",
+    // sample = "sample{day}.txt",
+    // sample_part1 = ...,
+    // sample_part2 = ...,
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {{
+    Ok(())
+}}
+
+pub fn part1(input: &str) -> usize {{
+    input.lines().count()
+}}
+
+pub fn part2(input: &str) -> usize {{
+    input.lines().count()
+}}
+"#,
+        year = year,
+        day = day
+    )
+}
+
+/// Source of the `dayN` binary, running the module of the day from the
+/// library of the year.
+pub fn binary(year: u32, day: u32) -> String {
+    format!(
+        "fn main() -> Result<(), Box<dyn std::error::Error>> {{\n    aoc{}::day{}::main()\n}}\n",
+        year, day
+    )
+}
+
+/// Files created for a new day in the crate of its year.
+pub fn files(crate_dir: impl AsRef<Path>, year: u32, day: u32) -> Vec<(PathBuf, String)> {
+    let crate_dir = crate_dir.as_ref();
+    let src = crate_dir.join("src");
+    vec![
+        (src.join(format!("day{}.rs", day)), template(year, day)),
+        (
+            src.join("bin").join(format!("day{}.rs", day)),
+            binary(year, day),
+        ),
+        (
+            crate_dir.join("input").join(format!("sample{}.txt", day)),
+            String::new(),
+        ),
+        (default_path(crate_dir, day), String::new()),
+    ]
+}
+
+/// Creates the module, the binary, the sample and the input placeholder of a
+/// new day and declares its module in the `lib.rs` of the year, refusing to
+/// overwrite any existing file.
+///
+/// The empty input placeholder is downloaded by the first run with `--fetch`,
+/// see [`crate::fetch::cached_input`].
+pub fn new_day(crate_dir: impl AsRef<Path>, year: u32, day: u32) -> anyhow::Result<Vec<PathBuf>> {
+    let crate_dir = crate_dir.as_ref();
+    let files = files(crate_dir, year, day);
+
+    let existing = files
+        .iter()
+        .filter(|(path, _)| path.exists())
+        .map(|(path, _)| path.display().to_string())
+        .collect::<Vec<_>>();
+    if !existing.is_empty() {
+        bail!("Not overwriting {}", existing.join(", "));
+    }
+
+    let lib = crate_dir.join("src").join("lib.rs");
+    let source =
+        std::fs::read_to_string(&lib).with_context(|| format!("Reading {}", lib.display()))?;
+    let registered = register(&source, day)?;
+
+    let mut created = vec![];
+    for (path, content) in files {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Creating the directory {}", dir.display()))?;
+        }
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .with_context(|| format!("Creating {}", path.display()))?;
+        created.push(path);
+    }
+
+    std::fs::write(&lib, registered).with_context(|| format!("Writing {}", lib.display()))?;
+    created.push(lib);
+
+    Ok(created)
+}
+
+/// Declares the module of the day in the source of the `lib.rs` of a year,
+/// keeping the modules sorted like rustfmt does. Its solution is then found by
+/// `aoc_attributes::solutions!`.
+pub fn register(source: &str, day: u32) -> anyhow::Result<String> {
+    let module = format!("day{}", day);
+    let mut lines = source.lines().map(String::from).collect::<Vec<_>>();
+
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let name = line.strip_prefix("pub mod ")?.strip_suffix(';')?;
+            Some((idx, name.to_string()))
+        })
+        .collect::<Vec<_>>();
+    if modules.iter().any(|(_, name)| *name == module) {
+        bail!("{} is already registered", module);
+    }
+
+    let position = modules
+        .iter()
+        .find(|(_, name)| *name > module)
+        .map(|(idx, _)| *idx)
+        .or_else(|| modules.last().map(|(idx, _)| idx + 1))
+        .context("No modules to register the day next to")?;
+    lines.insert(position, format!("pub mod {};", module));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_day, register};
+
+    const LIB: &str = r#"use aoc_runner::Solution;
+
+pub mod day1;
+pub mod day2;
+
+pub fn solutions() -> Vec<Solution> {
+    aoc_attributes::solutions!()
+}
+"#;
+
+    #[test]
+    fn test_register() {
+        let registered = register(LIB, 15).unwrap();
+
+        assert_eq!(
+            registered,
+            r#"use aoc_runner::Solution;
+
+pub mod day1;
+pub mod day15;
+pub mod day2;
+
+pub fn solutions() -> Vec<Solution> {
+    aoc_attributes::solutions!()
+}
+"#
+        );
+        assert!(register(&registered, 15).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("input")).unwrap();
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("input").join("day16.txt"), "mine").unwrap();
+
+        let unregistered = new_day(&dir, 2022, 15).unwrap_err();
+        let skipped = dir.join("src/day15.rs").exists();
+        std::fs::write(dir.join("src/lib.rs"), LIB).unwrap();
+
+        let created = new_day(&dir, 2022, 15).unwrap();
+        let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        let module = std::fs::read_to_string(dir.join("src/day15.rs")).unwrap();
+        let binary = std::fs::read_to_string(dir.join("src/bin/day15.rs")).unwrap();
+        let existing = new_day(&dir, 2022, 16).unwrap_err();
+        let again = new_day(&dir, 2022, 15).unwrap_err();
+        let untouched = dir.join("src/day16.rs").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(unregistered.to_string().ends_with("src/lib.rs"));
+        assert!(!skipped);
+        assert_eq!(
+            created,
+            vec![
+                dir.join("src/day15.rs"),
+                dir.join("src/bin/day15.rs"),
+                dir.join("input/sample15.txt"),
+                dir.join("input/day15.txt"),
+                dir.join("src/lib.rs"),
+            ]
+        );
+        assert!(lib.contains("pub mod day15;\n"));
+        assert!(module.contains("    day = 15,\n"));
+        assert!(module.contains("    // sample = \"sample15.txt\",\n"));
+        assert!(binary.contains("    aoc2022::day15::main()\n"));
+        assert!(existing.to_string().ends_with("input/day16.txt"));
+        assert!(again.to_string().starts_with("Not overwriting"));
+        assert!(!untouched);
+    }
+}
@@ -0,0 +1,174 @@
+use std::num::NonZeroUsize;
+
+use anyhow::Context;
+use structopt::StructOpt;
+
+use crate::{
+    fetch,
+    harness::{Config, Harness},
+    input::{InputOpts, Source},
+    report::{batch_summary, Report},
+};
+
+#[derive(Debug, StructOpt)]
+struct DayOpts {
+    #[structopt(flatten)]
+    input: InputOpts,
+    /// Runs each part N times after a warm up and reports the timings
+    #[structopt(long, value_name = "N")]
+    bench: Option<NonZeroUsize>,
+    /// Fails the input generator on any line it cannot parse, instead of
+    /// skipping it
+    #[structopt(long)]
+    strict: bool,
+    /// Downloads the default input first when it is missing, with the
+    /// configured session token
+    #[structopt(long)]
+    fetch: bool,
+    /// Prints the frames of the solving process instead of the answers, one
+    /// every N steps
+    #[structopt(long, value_name = "N", conflicts_with = "render")]
+    frames: Option<NonZeroUsize>,
+    /// Prints the last frame of the solving process instead of the answers
+    #[structopt(long)]
+    render: bool,
+}
+
+type Frames = fn(&str, NonZeroUsize) -> anyhow::Result<Vec<String>>;
+
+/// A day registered by `aoc_main`, runnable against any input.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    runner: fn(&str, &mut Harness),
+    frames: Option<Frames>,
+}
+
+impl Solution {
+    pub fn new(year: u32, day: u32, runner: fn(&str, &mut Harness)) -> Self {
+        Solution {
+            year,
+            day,
+            runner,
+            frames: None,
+        }
+    }
+
+    /// Frames of the solving process, one every given number of steps.
+    pub fn with_frames(self, frames: Frames) -> Self {
+        Solution {
+            frames: Some(frames),
+            ..self
+        }
+    }
+
+    /// Frames of the solving process on `input`, one every `every` steps or
+    /// only the last one without it.
+    pub fn frames(&self, input: &str, every: Option<NonZeroUsize>) -> anyhow::Result<Vec<String>> {
+        let frames = self
+            .frames
+            .with_context(|| format!("Day {} has no frames to render", self.day))?;
+        let mut frames = frames(input, every.unwrap_or(NonZeroUsize::MAX))?;
+        if every.is_none() {
+            frames = frames.pop().into_iter().collect();
+        }
+        Ok(frames)
+    }
+
+    pub fn run(&self, input: &str, config: Config) -> Report {
+        let mut harness = Harness::new(self.year, self.day, config);
+        (self.runner)(input, &mut harness);
+        harness.finish()
+    }
+
+    /// Runs the day against the input read from `source`, a failure to read
+    /// it is reported as a failed input generator.
+    pub fn run_source(&self, source: &Source, config: Config) -> Report {
+        match source.read() {
+            Ok(input) => self.run(&input, config),
+            Err(err) => {
+                let mut report = Report::new(self.year, self.day);
+                report.error = Some(format!("{:?}", err));
+                report
+            }
+        }
+    }
+
+    /// Entry point of the `dayN` binaries: runs the day against the inputs
+    /// given on the command line, exiting with a failure if any step fails.
+    ///
+    /// A single input is logged as it runs, several inputs are summarized in
+    /// a table with one row per input and part. With `--fetch` and no inputs
+    /// the default one is downloaded when missing, see [`fetch::cached_input`].
+    /// With `--frames` or `--render` the frames of the single input are
+    /// printed instead.
+    pub fn main(&self, base: &str) {
+        let opts = DayOpts::from_args();
+        let config = Config {
+            verbose: false,
+            bench: opts.bench,
+            strict: opts.strict,
+        };
+
+        let sources = if opts.fetch && opts.input.inputs.is_empty() {
+            fetch::Client::from_config()
+                .and_then(|client| fetch::cached_input(&client, base, self.year, self.day))
+                .and_then(|_| opts.input.sources(base, self.day))
+        } else {
+            opts.input.sources(base, self.day)
+        };
+
+        let failed = match sources {
+            Ok(sources) if opts.render || opts.frames.is_some() => {
+                match self.print_frames(&sources, opts.frames) {
+                    Ok(()) => false,
+                    Err(err) => {
+                        eprintln!("Error: {:?}", err);
+                        true
+                    }
+                }
+            }
+            Ok(sources) if sources.len() == 1 => match sources[0].read() {
+                Ok(input) => {
+                    let config = Config {
+                        verbose: true,
+                        ..config
+                    };
+                    self.run(&input, config).failed()
+                }
+                Err(err) => {
+                    eprintln!("Error: {:?}", err);
+                    true
+                }
+            },
+            Ok(sources) => {
+                let reports = sources
+                    .iter()
+                    .map(|source| (source.label(), self.run_source(source, config)))
+                    .collect::<Vec<_>>();
+                println!("AoC {} day {}\n", self.year, self.day);
+                print!("{}", batch_summary(&reports));
+                reports.iter().any(|(_, report)| report.failed())
+            }
+            Err(err) => {
+                eprintln!("Error: {:?}", err);
+                true
+            }
+        };
+
+        if failed {
+            std::process::exit(1);
+        }
+    }
+
+    fn print_frames(&self, sources: &[Source], every: Option<NonZeroUsize>) -> anyhow::Result<()> {
+        let source = match sources {
+            [source] => source,
+            _ => anyhow::bail!("Frames are rendered for a single input"),
+        };
+        let frames = self.frames(&source.read()?, every)?;
+        print!("{}", frames.join("\n"));
+        Ok(())
+    }
+}
@@ -0,0 +1,439 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::{Answers, ByPart},
+    fetch::Client,
+};
+
+/// What adventofcode.com said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked, another one was submitted too recently.
+    Wait,
+    /// The part is already solved or locked.
+    WrongLevel,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long to wait before submitting again.
+    pub wait: Option<Duration>,
+    pub message: String,
+}
+
+impl std::str::FromStr for Response {
+    type Err = anyhow::Error;
+
+    /// Parses the html page returned when posting an answer.
+    fn from_str(html: &str) -> Result<Self, Self::Err> {
+        let message = article_text(html);
+
+        let outcome = if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if message.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if message.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::Wait
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            bail!("Unexpected response: {}", message);
+        };
+
+        Ok(Response {
+            outcome,
+            wait: parse_wait(&message),
+            message,
+        })
+    }
+}
+
+/// Text of the `<article>` of the page, without tags and extra whitespaces.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 1m 5s left to wait" and "please wait one minute / 5
+/// minutes before trying again", in any case.
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some((_, rest)) = message.split_once("You have ") {
+        let (left, _) = rest.split_once(" left to wait")?;
+        return left
+            .split_whitespace()
+            .map(
+                |amount| match amount.split_at(amount.len().checked_sub(1)?) {
+                    (n, "h") => n.parse::<u64>().ok().map(|n| n * 3600),
+                    (n, "m") => n.parse::<u64>().ok().map(|n| n * 60),
+                    (n, "s") => n.parse::<u64>().ok(),
+                    _ => None,
+                },
+            )
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let message = message.to_lowercase();
+    let (_, rest) = message.split_once("please wait ")?;
+    let (amount, _) = rest.split_once(" minute")?;
+    let minutes = match amount {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// Wrong answers submitted for a part.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempts {
+    /// The answer adventofcode.com accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+}
+
+impl Attempts {
+    /// Fails when the part is already solved or the answer is known to be
+    /// wrong: already submitted, or out of the bounds given by the answers
+    /// that were too high or too low.
+    pub fn check(&self, answer: &str) -> anyhow::Result<()> {
+        match &self.accepted {
+            Some(accepted) if accepted == answer => bail!("{} was already accepted", answer),
+            Some(accepted) => bail!("{} is wrong, the accepted answer is {}", answer, accepted),
+            None => {}
+        }
+
+        for (attempts, verdict) in [
+            (&self.too_high, "too high"),
+            (&self.too_low, "too low"),
+            (&self.wrong, "wrong"),
+        ] {
+            if attempts.iter().any(|attempt| attempt == answer) {
+                bail!("{} was already submitted and it was {}", answer, verdict);
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let lowest_high = numbers(&self.too_high).min();
+            if let Some(bound) = lowest_high.filter(|bound| value >= *bound) {
+                bail!("{} is not lower than {}, which was too high", answer, bound);
+            }
+            let highest_low = numbers(&self.too_low).max();
+            if let Some(bound) = highest_low.filter(|bound| value <= *bound) {
+                bail!("{} is not higher than {}, which was too low", answer, bound);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn numbers(attempts: &[String]) -> impl Iterator<Item = i128> + '_ {
+    attempts.iter().filter_map(|attempt| attempt.parse().ok())
+}
+
+/// Accepted and wrong answers and rate limit of a year, stored in
+/// `submissions.toml`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submissions {
+    /// Unix time before which adventofcode.com refuses answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default)]
+    days: ByPart<Attempts>,
+}
+
+impl Submissions {
+    pub fn path(base: impl AsRef<Path>) -> PathBuf {
+        base.as_ref().join("submissions.toml")
+    }
+
+    /// Loads the submissions file, a missing file has no submissions.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Submissions> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Submissions::default());
+        }
+        std::fs::read_to_string(path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| Ok(toml::from_str(&content)?))
+            .with_context(|| format!("Reading submissions from {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Writing submissions to {}", path.display()))
+    }
+
+    pub fn attempts(&self, day: u32, part: u8) -> Attempts {
+        self.days.get(day, part).cloned().unwrap_or_default()
+    }
+
+    /// Fails when the answer should not be submitted at `now`, see
+    /// [`Attempts::check`].
+    pub fn check(&self, day: u32, part: u8, answer: &str, now: u64) -> anyhow::Result<()> {
+        if let Some(wait) = self.wait_until.filter(|until| *until > now) {
+            bail!("Too many submissions, wait {}s", wait - now);
+        }
+        self.attempts(day, part).check(answer)
+    }
+
+    pub fn record(&mut self, day: u32, part: u8, answer: &str, response: &Response, now: u64) {
+        let attempts = match response.outcome {
+            Outcome::Correct => {
+                self.days.entry(day, part).accepted = Some(answer.to_string());
+                None
+            }
+            Outcome::TooHigh => Some(&mut self.days.entry(day, part).too_high),
+            Outcome::TooLow => Some(&mut self.days.entry(day, part).too_low),
+            Outcome::Wrong => Some(&mut self.days.entry(day, part).wrong),
+            Outcome::Wait | Outcome::WrongLevel => None,
+        };
+        if let Some(attempts) = attempts {
+            attempts.push(answer.to_string());
+        }
+        self.wait_until = response.wait.map(|wait| now + wait.as_secs());
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_secs())
+}
+
+/// Submits the answer of a part unless it is known to be wrong from
+/// `submissions.toml`, recording the outcome there and correct answers in
+/// `answers.toml` under `base`.
+///
+/// The answers recorded by `run --record` are only a local baseline, they do
+/// not stop a submission.
+pub fn submit(
+    client: &Client,
+    base: impl AsRef<Path>,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> anyhow::Result<Response> {
+    let path = Submissions::path(&base);
+    let mut submissions = Submissions::load(&path)?;
+    submissions
+        .check(day, part, answer, now())
+        .with_context(|| anyhow!("Not submitting part {} of {} day {}", part, year, day))?;
+
+    let response: Response = client.answer(year, day, part, answer)?.parse()?;
+
+    submissions.record(day, part, answer, &response, now());
+    submissions.save(&path)?;
+    if response.outcome == Outcome::Correct {
+        let answers_path = Answers::path(&base);
+        let mut answers = Answers::load(&answers_path)?;
+        answers.set(day, part, answer);
+        answers.save(&answers_path)?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        answers::Answers,
+        fetch::Client,
+        harness::{Config, Harness},
+        solution::Solution,
+        stub::serve,
+    };
+
+    use super::{submit, Attempts, Outcome, Response, Submissions};
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high.  If you're stuck, make sure you're using the full input data. Please wait one \
+        minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main>";
+    const WAIT: &str = "<main><article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 5s left to wait. \
+        <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main>";
+    const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make \
+        sure you're using the full input data; there are also some general tips on the \
+        <a href=\"/2022/about\">about page</a>, or you can ask for hints on the \
+        <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  \
+        Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes \
+        before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main>";
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span \
+        class=\"day-success\">one gold star</span> closer to saving your vacation.</p></article></main>";
+
+    #[test]
+    fn test_parse_response() {
+        let too_high: Response = TOO_HIGH.parse().unwrap();
+        let wait: Response = WAIT.parse().unwrap();
+        let wrong: Response = WRONG.parse().unwrap();
+        let correct: Response = CORRECT.parse().unwrap();
+
+        assert_eq!(too_high.outcome, Outcome::TooHigh);
+        assert_eq!(too_high.wait, Some(Duration::from_secs(60)));
+        assert_eq!(wait.outcome, Outcome::Wait);
+        assert_eq!(wait.wait, Some(Duration::from_secs(65)));
+        assert_eq!(wrong.outcome, Outcome::Wrong);
+        assert_eq!(wrong.wait, Some(Duration::from_secs(300)));
+        assert_eq!(correct.outcome, Outcome::Correct);
+        assert_eq!(correct.wait, None);
+        assert_eq!(
+            correct.message,
+            "That's the right answer! You are one gold star closer to saving your vacation."
+        );
+        assert!("<html>Not found</html>".parse::<Response>().is_err());
+    }
+
+    #[test]
+    fn test_attempts_check() {
+        let attempts = Attempts {
+            accepted: None,
+            too_high: vec!["500".to_string(), "300".to_string()],
+            too_low: vec!["100".to_string()],
+            wrong: vec!["ABC".to_string()],
+        };
+
+        assert!(attempts.check("200").is_ok());
+        assert!(attempts.check("XYZ").is_ok());
+        assert_eq!(
+            attempts.check("ABC").unwrap_err().to_string(),
+            "ABC was already submitted and it was wrong"
+        );
+        assert_eq!(
+            attempts.check("400").unwrap_err().to_string(),
+            "400 is not lower than 300, which was too high"
+        );
+        assert_eq!(
+            attempts.check("100").unwrap_err().to_string(),
+            "100 was already submitted and it was too low"
+        );
+        assert_eq!(
+            attempts.check("50").unwrap_err().to_string(),
+            "50 is not higher than 100, which was too low"
+        );
+
+        let solved = Attempts {
+            accepted: Some("200".to_string()),
+            ..attempts
+        };
+        assert_eq!(
+            solved.check("200").unwrap_err().to_string(),
+            "200 was already accepted"
+        );
+        assert_eq!(
+            solved.check("XYZ").unwrap_err().to_string(),
+            "XYZ is wrong, the accepted answer is 200"
+        );
+    }
+
+    #[test]
+    fn test_submissions_wait() {
+        let mut submissions = Submissions::default();
+        let response: Response = WAIT.parse().unwrap();
+
+        submissions.record(1, 1, "42", &response, 1000);
+
+        assert!(submissions.check(1, 1, "42", 1064).is_err());
+        assert!(submissions.check(1, 1, "42", 1065).is_ok());
+    }
+
+    #[test]
+    fn test_submit() {
+        let base = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        std::fs::create_dir_all(&base).unwrap();
+        let (url, server) = serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new("cafe").with_base_url(url);
+
+        let too_high = submit(&client, &base, 2022, 1, 1, "70000").unwrap();
+        let mut submissions = Submissions::load(Submissions::path(&base)).unwrap();
+        let refused = submit(&client, &base, 2022, 1, 1, "80000");
+        submissions.wait_until = None;
+        submissions.save(Submissions::path(&base)).unwrap();
+        let refused_bound = submit(&client, &base, 2022, 1, 1, "80000");
+        let correct = submit(&client, &base, 2022, 1, 1, "68292").unwrap();
+        let answers = Answers::load(Answers::path(&base)).unwrap();
+        let solved = Submissions::load(Submissions::path(&base)).unwrap();
+        let accepted = submit(&client, &base, 2022, 1, 1, "68292");
+        std::fs::remove_dir_all(&base).unwrap();
+
+        assert_eq!(too_high.outcome, Outcome::TooHigh);
+        assert_eq!(submissions.attempts(1, 1).too_high, vec!["70000"]);
+        assert!(format!("{:?}", refused.unwrap_err()).contains("Too many submissions"));
+        assert!(format!("{:?}", refused_bound.unwrap_err()).contains("which was too high"));
+        assert_eq!(correct.outcome, Outcome::Correct);
+        assert_eq!(answers.get(1, 1), Some("68292"));
+        assert_eq!(solved.attempts(1, 1).accepted, Some("68292".to_string()));
+        assert!(format!("{:?}", accepted.unwrap_err()).contains("68292 was already accepted"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].line, "POST /2022/day/1/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=1&answer=70000");
+        assert_eq!(requests[1].body, "level=1&answer=68292");
+    }
+
+    fn wrong_baseline(_input: &str, harness: &mut Harness) {
+        harness.part(1, || Ok(crate::rendered!(70000)));
+    }
+
+    #[test]
+    fn test_submit_after_record() {
+        let base = std::env::temp_dir().join(format!("aoc-record-{}", std::process::id()));
+        std::fs::create_dir_all(&base).unwrap();
+        let (url, server) = serve(vec![(200, CORRECT)]);
+        let client = Client::new("cafe").with_base_url(url);
+
+        // What `run --record` does with a wrong answer that was never submitted
+        let report = Solution::new(2022, 1, wrong_baseline).run("", Config::default());
+        let mut answers = Answers::default();
+        answers.record(&[report]);
+        answers.save(Answers::path(&base)).unwrap();
+
+        let correct = submit(&client, &base, 2022, 1, 1, "68292");
+        let answers = Answers::load(Answers::path(&base)).unwrap();
+        std::fs::remove_dir_all(&base).unwrap();
+
+        assert_eq!(correct.unwrap().outcome, Outcome::Correct);
+        assert_eq!(answers.get(1, 1), Some("68292"));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+}
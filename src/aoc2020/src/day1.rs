@@ -0,0 +1,36 @@
+use aoc_attributes::aoc_main;
+use itertools::Itertools;
+
+#[aoc_main(
+    year = 2020,
+    day = 1,
+    generator = "input_generator",
+    part1 = "part1",
+    part2 = "part2"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn input_generator(input: &str) -> anyhow::Result<Vec<i32>> {
+    input
+        .lines()
+        .map(|l| l.parse().map_err(anyhow::Error::from))
+        .collect()
+}
+
+pub fn part1(input: &[i32]) -> i32 {
+    combinator_sum_k(input, 2, 2020).iter().product()
+}
+
+pub fn part2(input: &[i32]) -> i32 {
+    combinator_sum_k(input, 3, 2020).iter().product()
+}
+fn combinator_sum_k(input: &[i32], combinations: usize, n: i32) -> Vec<i32> {
+    input
+        .iter()
+        .copied()
+        .combinations(combinations)
+        .find(|v| v.iter().sum::<i32>() == n)
+        .unwrap_or_else(Vec::new)
+}
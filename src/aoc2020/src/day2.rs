@@ -0,0 +1,56 @@
+use aoc_attributes::{aoc_main, AocParse};
+
+use aoc_common::parse::{parse_lines, LineErrors};
+
+#[aoc_main(
+    year = 2020,
+    day = 2,
+    generator = "input_generator",
+    part1 = "part1",
+    part2 = "part2"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn input_generator(input: &str, strict: bool) -> Result<Vec<PwdInput>, LineErrors> {
+    parse_lines(input, str::parse, strict)
+}
+
+#[derive(Debug, AocParse)]
+#[aoc(pattern = "{low}-{high} {letter}: {pwd}")]
+pub struct PwdInput {
+    low: usize,
+    high: usize,
+    letter: char,
+    pwd: String,
+}
+
+pub fn part1(input: &[PwdInput]) -> usize {
+    input.iter().filter(|pwd| is_valid_one(pwd)).count()
+}
+
+pub fn is_valid_one(input: &PwdInput) -> bool {
+    let count = input
+        .pwd
+        .chars()
+        .filter(|letter| *letter == input.letter)
+        .count();
+
+    (input.low..=input.high).contains(&count)
+}
+
+pub fn part2(input: &[PwdInput]) -> usize {
+    input.iter().filter(|pwd| is_valid_two(pwd)).count()
+}
+
+fn is_valid_two(input: &PwdInput) -> bool {
+    input
+        .pwd
+        .char_indices()
+        .filter(|(idx, letter)| {
+            (*idx == input.low - 1 || *idx == input.high - 1) && *letter == input.letter
+        })
+        .count()
+        == 1
+}
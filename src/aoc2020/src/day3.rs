@@ -0,0 +1,33 @@
+use aoc_attributes::aoc_main;
+use aoc_common::{Grid, Position};
+
+#[aoc_main(year = 2020, day = 3, part1 = "part1", part2 = "part2")]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn part1(input: &str) -> anyhow::Result<usize> {
+    Ok(traverse(&forest(input)?, 3, 1))
+}
+
+pub fn part2(input: &str) -> anyhow::Result<usize> {
+    let forest = forest(input)?;
+    Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .fold(1, |acc, (right, down)| {
+            acc * traverse(&forest, *right, *down)
+        }))
+}
+
+/// Trees of the map, which repeats itself to the right.
+fn forest(input: &str) -> anyhow::Result<Grid<bool>> {
+    Grid::parse(input, |_, c| Ok(c == '#'))
+}
+
+fn traverse(forest: &Grid<bool>, right: usize, down: usize) -> usize {
+    (0..forest.height())
+        .step_by(down)
+        .skip(1)
+        .filter(|row| forest[Position::new(*row, row / down * right % forest.width())])
+        .count()
+}
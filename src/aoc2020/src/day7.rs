@@ -0,0 +1,112 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_attributes::aoc_main;
+use aoc_common::parse::{num_parser, parse_lines, LineErrors};
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::space1,
+    multi::separated_list0,
+    sequence::tuple,
+    IResult,
+};
+
+#[aoc_main(
+    year = 2020,
+    day = 7,
+    generator = "input_generator",
+    part1 = "part_one",
+    part2 = "part_two"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct Bag<'a> {
+    name: &'a str,
+    bags: HashMap<&'a str, i32>,
+}
+
+pub fn input_generator(input: &str, strict: bool) -> Result<Vec<Bag<'_>>, LineErrors> {
+    let mut parser = bag_parser();
+    parse_lines(input, |line| parser(line).map(|(_, bag)| bag), strict)
+}
+
+pub fn part_one(input: &[Bag]) -> usize {
+    let parents = input.iter().fold(HashMap::new(), |mut acc, item| {
+        for name in item.bags.keys() {
+            acc.entry(*name)
+                .or_insert_with(HashSet::new)
+                .insert(item.name);
+        }
+
+        acc
+    });
+
+    count_parents(&parents, "shiny gold").len()
+}
+
+fn count_parents<'a>(
+    parents: &'a HashMap<&'a str, HashSet<&'a str>>,
+    current: &'a str,
+) -> HashSet<&'a str> {
+    match parents.get(current) {
+        Some(parents_for) => parents_for.iter().fold(HashSet::new(), |mut acc, item| {
+            acc.extend(count_parents(parents, item));
+            acc.insert(item);
+            acc
+        }),
+        None => HashSet::new(),
+    }
+}
+
+fn count_bags<'a>(bags: &HashMap<&'a str, &'a Bag<'a>>, current: &'a str) -> i32 {
+    match bags.get(current) {
+        Some(bag) => bag.bags.iter().fold(0, |acc, (b, count)| {
+            acc + count + (count * count_bags(bags, b))
+        }),
+
+        None => 0,
+    }
+}
+
+pub fn part_two(input: &[Bag]) -> i32 {
+    let parents = input.iter().fold(HashMap::new(), |mut acc, item| {
+        acc.insert(item.name, item);
+
+        acc
+    });
+
+    count_bags(&parents, "shiny gold")
+}
+
+fn single_bag_parser<'a>() -> impl FnMut(&'a str) -> IResult<&str, (&str, i32)> {
+    move |input| {
+        let (remaining, (_, count, _, color)) =
+            tuple((space1, num_parser::<i32>(), space1, take_until("bag")))(input)?;
+
+        let bag_tag = if count > 1 { "bags" } else { "bag" };
+
+        let (remaining, _) = tag(bag_tag)(remaining)?;
+
+        Ok((remaining, (color.trim(), count)))
+    }
+}
+
+fn bag_parser<'a>() -> impl FnMut(&'a str) -> IResult<&str, Bag<'a>> {
+    move |input| {
+        let (remaining, (bag, _, colors)) = tuple((
+            take_until("bags"),
+            tag("bags contain"),
+            separated_list0(tag(","), single_bag_parser()),
+        ))(input)?;
+
+        Ok((
+            remaining,
+            Bag {
+                name: bag.trim(),
+                bags: colors.into_iter().collect(),
+            },
+        ))
+    }
+}
@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use aoc_attributes::aoc_main;
+use aoc_common::console::{Console, ExecutionResult, Program, Repair};
+
+use anyhow::Result;
+
+#[aoc_main(
+    year = 2020,
+    day = 8,
+    generator = "input_generator",
+    part1 = "part_one",
+    part2 = "part_two"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn input_generator(input: &str) -> Result<Program> {
+    Program::from_str(input)
+}
+
+pub fn part_one(program: &Program) -> Result<i64> {
+    let mut console = Console::new(program);
+
+    match console.run() {
+        ExecutionResult::InfiniteLoop { .. } => Ok(console.registers().acc),
+        result => anyhow::bail!(
+            "Expected an infinite loop, the boot code stopped with {:?}",
+            result
+        ),
+    }
+}
+
+pub fn part_two(program: &Program) -> Result<i64> {
+    repaired_acc(program, Repair::Analytic)
+}
+
+/// Accumulator of the boot code halting once fixed with `repair`.
+fn repaired_acc(program: &Program, repair: Repair) -> Result<i64> {
+    let fix = repair
+        .find(program)
+        .ok_or_else(|| anyhow::anyhow!("No single flip makes the boot code halt"))?;
+
+    let mut console = Console::new(program).with_patch(fix.pc, fix.op);
+    match console.run() {
+        ExecutionResult::Halted => Ok(console.registers().acc),
+        result => anyhow::bail!("{:?} did not fix the boot code: {:?}", fix, result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::console::{Program, Repair};
+
+    use super::{input_generator, repaired_acc};
+
+    static INPUT: &str = include_str!("../input/day8.txt");
+
+    #[test]
+    fn test_repair_strategies() {
+        let program: Program = input_generator(INPUT).unwrap();
+
+        let fix = Repair::BruteForce.find(&program);
+        assert!(fix.is_some());
+        assert_eq!(Repair::Analytic.find(&program), fix);
+        assert_eq!(
+            repaired_acc(&program, Repair::Analytic).unwrap(),
+            repaired_acc(&program, Repair::BruteForce).unwrap()
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_attributes::aoc_main;
+use itertools::Itertools;
+
+use anyhow::Result;
+
+#[aoc_main(
+    year = 2020,
+    day = 9,
+    generator = "input_generator",
+    part1 = "part_one",
+    part2 = "part_two"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+fn input_generator(input: &str) -> Result<Vec<usize>> {
+    input
+        .lines()
+        .map(|line| line.parse::<usize>().map_err(anyhow::Error::from))
+        .collect()
+}
+pub fn part_one(input: &[usize]) -> usize {
+    find_invalid_number(input)
+}
+
+fn find_invalid_number(numbers: &[usize]) -> usize {
+    numbers
+        .windows(26)
+        .find(|wnd| {
+            wnd[0..25]
+                .iter()
+                .tuple_combinations()
+                .all(|(a, b)| a + b != wnd[25])
+        })
+        .unwrap()[25]
+}
+
+pub fn part_two(input: &[usize]) -> usize {
+    let invalid = find_invalid_number(input);
+
+    let (mut min, mut max) = (0, 0);
+    'outer: for (i, a) in input.iter().enumerate() {
+        min = *a;
+        max = *a;
+        let mut sum = *a;
+        for b in input[i + 1..].iter() {
+            min = *b.min(&min);
+            max = *b.max(&max);
+            sum += b;
+            if sum == invalid {
+                break 'outer;
+            } else if sum > invalid {
+                break;
+            }
+        }
+    }
+
+    min + max
+}
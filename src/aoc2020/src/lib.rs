@@ -1 +1,17 @@
-pub mod utils;
+use aoc_runner::Solution;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// Solutions of the year, one per `src/dayN.rs` module, run by the year
+/// runner.
+pub fn solutions() -> Vec<Solution> {
+    aoc_attributes::solutions!()
+}
@@ -0,0 +1,231 @@
+use std::{
+    cell::{Ref, RefCell},
+    collections::VecDeque,
+    rc::Rc,
+};
+
+use aoc_attributes::aoc_main;
+use aoc_common::parse::{blocks_of, num_parser};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, newline},
+    combinator::{map, value},
+    multi::separated_list1,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+#[aoc_main(
+    year = 2022,
+    day = 11,
+    part1 = "part1",
+    part2 = "part2",
+    sample = "sample11.txt",
+    sample_part1 = 10605,
+    sample_part2 = 2713310158
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+type Cooler = Box<dyn Fn(Item) -> Item>;
+type CoolerFactory = Box<dyn Fn(&Vec<Rc<RefCell<Monkey>>>) -> Cooler>;
+
+pub type Item = i64;
+pub type MonkeyN = usize;
+
+pub struct Game {
+    rounds: usize,
+    monkeys: Vec<Rc<RefCell<Monkey>>>,
+    cooler: Cooler,
+}
+
+impl Game {
+    pub fn play(&mut self) {
+        for _ in 0..self.rounds {
+            self.round();
+        }
+    }
+
+    fn round(&mut self) {
+        for monkey in &self.monkeys {
+            while let Some((idx, item)) = monkey.borrow_mut().inspect(&self.cooler) {
+                self.monkeys[idx].borrow_mut().add_item(item);
+            }
+        }
+    }
+
+    fn monkeys(&self) -> impl Iterator<Item = Ref<'_, Monkey>> {
+        self.monkeys.iter().map(|m| m.borrow())
+    }
+}
+
+#[derive(Debug)]
+pub struct Monkey {
+    items: VecDeque<Item>,
+    op: Op,
+    cond: Cond,
+    inspections: Item,
+}
+
+impl Monkey {
+    pub fn new(op: Op, cond: Cond) -> Self {
+        Monkey {
+            items: VecDeque::new(),
+            op,
+            cond,
+            inspections: 0,
+        }
+    }
+    fn inspect(&mut self, cooler: &dyn Fn(Item) -> Item) -> Option<(MonkeyN, Item)> {
+        if let Some(next) = self.items.pop_front() {
+            self.inspections += 1;
+            let item = cooler(self.op.apply(next));
+            Some((self.cond.apply(item), item))
+        } else {
+            None
+        }
+    }
+
+    fn add_item(&mut self, item: Item) {
+        self.items.push_back(item);
+    }
+
+    pub fn inspections(&self) -> Item {
+        self.inspections
+    }
+}
+
+#[derive(Debug)]
+pub struct Cond {
+    test: Test,
+    t: MonkeyN,
+    f: MonkeyN,
+}
+
+impl Cond {
+    pub fn new(test: Test, t: MonkeyN, f: MonkeyN) -> Self {
+        Self { test, t, f }
+    }
+
+    fn apply(&self, item: Item) -> MonkeyN {
+        if self.test.test(item) {
+            self.t
+        } else {
+            self.f
+        }
+    }
+}
+#[derive(Debug, Clone)]
+pub enum Op {
+    Plus(Item),
+    Mul(Item),
+    MulSelf,
+    AddSelf,
+}
+
+impl Op {
+    fn apply(&self, item: Item) -> Item {
+        match self {
+            Op::Plus(val) => val + item,
+            Op::Mul(val) => val * item,
+            Op::MulSelf => item * item,
+            Op::AddSelf => item + item,
+        }
+    }
+}
+#[derive(Debug)]
+pub enum Test {
+    Divisible(Item),
+}
+
+impl Test {
+    fn test(&self, item: Item) -> bool {
+        match self {
+            Test::Divisible(val) => item % val == 0,
+        }
+    }
+
+    fn item(&self) -> Item {
+        match self {
+            Test::Divisible(i) => *i,
+        }
+    }
+}
+
+pub fn part1(input: &str) -> anyhow::Result<Item> {
+    solve(input, 20, Box::new(|_| Box::new(|val| val / 3)))
+}
+
+pub fn solve(input: &str, rounds: usize, cooler: CoolerFactory) -> anyhow::Result<Item> {
+    let mut game = Game::from_str(input, rounds, cooler)?;
+
+    game.play();
+
+    Ok(game
+        .monkeys()
+        .map(|m| m.inspections())
+        .sorted_by(|first, second| second.cmp(first))
+        .take(2)
+        .product())
+}
+
+pub fn part2(input: &str) -> anyhow::Result<Item> {
+    solve(
+        input,
+        10000,
+        Box::new(|g| {
+            let factors = g
+                .iter()
+                .map(|m| m.borrow().cond.test.item())
+                .product::<Item>();
+            Box::new(move |val| val % factors)
+        }),
+    )
+}
+
+fn monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = tuple((tag("Monkey "), digit1, tag(":\n")))(input)?;
+    let (input, items) = delimited(
+        tag("  Starting items: "),
+        separated_list1(tag(", "), num_parser()),
+        newline,
+    )(input)?;
+    let (input, op) = delimited(tag("  Operation: new = old "), operation, newline)(input)?;
+    let (input, test) = delimited(tag("  Test: divisible by "), num_parser(), newline)(input)?;
+    let (input, t) = delimited(tag("    If true: throw to monkey "), num_parser(), newline)(input)?;
+    let (input, f) = preceded(tag("    If false: throw to monkey "), num_parser())(input)?;
+
+    let mut monkey = Monkey::new(op, Cond::new(Test::Divisible(test), t, f));
+    for item in items {
+        monkey.add_item(item);
+    }
+    Ok((input, monkey))
+}
+
+fn operation(input: &str) -> IResult<&str, Op> {
+    alt((
+        value(Op::MulSelf, tag("* old")),
+        value(Op::AddSelf, tag("+ old")),
+        map(preceded(tag("* "), num_parser()), Op::Mul),
+        map(preceded(tag("+ "), num_parser()), Op::Plus),
+    ))(input)
+}
+
+impl Game {
+    fn from_str(s: &str, rounds: usize, cooler: CoolerFactory) -> anyhow::Result<Self> {
+        let monkeys = blocks_of(s, monkey)?
+            .into_iter()
+            .map(|monkey| Rc::new(RefCell::new(monkey)))
+            .collect::<Vec<_>>();
+
+        let cooler = cooler(&monkeys);
+        Ok(Game {
+            rounds,
+            monkeys,
+            cooler,
+        })
+    }
+}
@@ -0,0 +1,214 @@
+use std::num::NonZeroUsize;
+
+use aoc_attributes::aoc_main;
+use aoc_common::{
+    search::{bfs, Path},
+    Direction4, Grid, Position,
+};
+
+#[aoc_main(
+    year = 2022,
+    day = 12,
+    part1 = "part1",
+    part2 = "part2",
+    sample = "sample12.txt",
+    sample_part1 = 31,
+    sample_part2 = 29,
+    frames = "frames"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn part1(input: &str) -> usize {
+    let maze = maze(input);
+    let start = maze.square(Square::S);
+    maze.run(start).unwrap().cost
+}
+pub fn part2(input: &str) -> usize {
+    let maze = maze(input);
+    let starts = maze
+        .grid
+        .positions(|square| square.elevation() == 'a' as i32);
+    maze.run(starts).unwrap().cost
+}
+
+/// Frames of the search of part 1, see [`Maze::frames`].
+pub fn frames(input: &str, every: NonZeroUsize) -> Vec<String> {
+    let maze = maze(input);
+    maze.frames(maze.square(Square::S), every)
+}
+
+pub struct Maze {
+    grid: Grid<Square>,
+}
+
+impl Maze {
+    fn square(&self, square: Square) -> impl Iterator<Item = Position> + '_ {
+        self.grid.positions(move |s| *s == square)
+    }
+
+    /// Shortest path from any of the starts to the end, climbing at most one
+    /// elevation at each step.
+    pub fn run(&self, starts: impl IntoIterator<Item = Position>) -> Option<Path<Position, usize>> {
+        self.explore(starts, |_| {})
+    }
+
+    /// Like [`Maze::run`], calling `visit` with each point in the order the
+    /// search expands them.
+    pub fn explore(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        mut visit: impl FnMut(Position),
+    ) -> Option<Path<Position, usize>> {
+        bfs(
+            starts,
+            |&from| {
+                visit(from);
+                self.grid
+                    .neighbours(from)
+                    .filter(move |to| self.grid[*to].gap(&self.grid[from]) <= 1)
+            },
+            |point| self.grid[*point] == Square::E,
+        )
+    }
+
+    /// The maze with the path overlaid as arrows pointing to the next step.
+    pub fn render(&self, path: &[Position]) -> String {
+        let mut canvas = self.grid.map(Square::symbol);
+        for step in path.windows(2) {
+            canvas[step[0]] = arrow(step[0], step[1]);
+        }
+        canvas.to_string()
+    }
+
+    /// Frames of the search from the starts, marking with `.` the next
+    /// `every` points expanded at each frame, then the path found.
+    pub fn frames(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        every: NonZeroUsize,
+    ) -> Vec<String> {
+        let mut visited = vec![];
+        let path = self.explore(starts, |point| visited.push(point));
+
+        let mut canvas = self.grid.map(Square::symbol);
+        let mut frames = vec![];
+        for chunk in visited.chunks(every.get()) {
+            for point in chunk {
+                canvas[*point] = '.';
+            }
+            frames.push(canvas.to_string());
+        }
+        if let Some(path) = path {
+            frames.push(self.render(&path.nodes));
+        }
+        frames
+    }
+}
+
+fn arrow(from: Position, to: Position) -> char {
+    match Direction4::ALL
+        .into_iter()
+        .find(|direction| from.step(*direction) == Some(to))
+    {
+        Some(Direction4::Up) => '^',
+        Some(Direction4::Right) => '>',
+        Some(Direction4::Down) => 'v',
+        Some(Direction4::Left) => '<',
+        None => '?',
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Square {
+    S,
+    E,
+    X(char),
+}
+
+impl Square {
+    fn gap(&self, other: &Square) -> i32 {
+        self.elevation() - other.elevation()
+    }
+
+    fn symbol(&self) -> char {
+        match self {
+            Square::S => 'S',
+            Square::E => 'E',
+            Square::X(x) => *x,
+        }
+    }
+
+    fn elevation(&self) -> i32 {
+        let c = match self {
+            Square::S => 'a',
+            Square::E => 'z',
+            Square::X(x) => *x,
+        };
+
+        c as i32
+    }
+}
+
+fn maze(input: &str) -> Maze {
+    Maze {
+        grid: input.parse().unwrap(),
+    }
+}
+
+impl From<char> for Square {
+    fn from(c: char) -> Self {
+        match c {
+            'S' => Square::S,
+            'E' => Square::E,
+            _ => Square::X(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::{maze, solution, Square};
+
+    static INPUT: &str = include_str!("../input/sample12.txt");
+
+    #[test]
+    fn test_render() {
+        let maze = maze(INPUT);
+        let path = maze.run(maze.square(Square::S)).unwrap();
+
+        assert_eq!(
+            maze.render(&path.nodes),
+            "\
+>>vv<<<<
+abvvv<<^
+acvv>E^^
+acv>>>^^
+ab>>>>>^
+"
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let maze = maze(INPUT);
+        let path = maze.run(maze.square(Square::S)).unwrap();
+        let every = |n| NonZeroUsize::new(n).unwrap();
+
+        let frames = maze.frames(maze.square(Square::S), every(1));
+        assert_eq!(frames.len(), 40);
+        assert_eq!(frames.last(), Some(&maze.render(&path.nodes)));
+        assert_eq!(frames[0].matches('.').count(), 1);
+        assert_eq!(maze.frames(maze.square(Square::S), every(5)).len(), 9);
+
+        let solution = solution();
+        assert_eq!(solution.frames(INPUT, Some(every(5))).unwrap().len(), 9);
+        assert_eq!(
+            solution.frames(INPUT, None).unwrap(),
+            vec![maze.render(&path.nodes)]
+        );
+    }
+}
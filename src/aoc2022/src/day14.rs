@@ -0,0 +1,116 @@
+use aoc_attributes::aoc_main;
+use aoc_common::{parse::pair_parser, Direction8, Point, SparseGrid};
+use itertools::Itertools;
+
+const DIRECTIONS: [Direction8; 3] = [
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::DownRight,
+];
+
+const SOURCE: Point<i64> = Point::new(0, 500);
+
+#[aoc_main(
+    year = 2022,
+    day = 14,
+    part1 = "part1",
+    part2 = "part2",
+    sample = "sample14.txt",
+    sample_part1 = 24,
+    sample_part2 = 93
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn part1(input: &str) -> usize {
+    pour(&mut cave(input), None)
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut cave = cave(input);
+    let floor = cave.bounds().map_or(0, |bounds| bounds.max.row) + 2;
+    pour(&mut cave, Some(floor))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Sand,
+    Start,
+    Rock,
+    Air,
+}
+
+fn cave(input: &str) -> SparseGrid<Cell> {
+    let mut cave = SparseGrid::new(Cell::Air);
+    cave.set(SOURCE, Cell::Start);
+
+    for line in parse_lines(input) {
+        for (from, to) in line.0.iter().tuple_windows() {
+            for row in from.row.min(to.row)..=from.row.max(to.row) {
+                for col in from.col.min(to.col)..=from.col.max(to.col) {
+                    cave.set(Point::new(row, col), Cell::Rock);
+                }
+            }
+        }
+    }
+    cave
+}
+
+/// Drops sand from the source until a unit falls into the abyss below the
+/// rocks or, when there is a floor, until the source is blocked. Returns the
+/// units of sand at rest.
+///
+/// Each unit follows the path of the previous one up to where it came to rest,
+/// so it starts falling from the last point of that path.
+fn pour(cave: &mut SparseGrid<Cell>, floor: Option<i64>) -> usize {
+    let abyss = cave.bounds().map_or(0, |bounds| bounds.max.row);
+    let mut path = vec![SOURCE];
+    let mut units = 0;
+
+    while let Some(&(mut sand)) = path.last() {
+        loop {
+            if floor.is_none() && sand.row > abyss {
+                return units;
+            }
+            let next = DIRECTIONS
+                .iter()
+                .filter_map(|direction| sand.step(*direction))
+                .find(|next| {
+                    *cave.get(*next) == Cell::Air && floor.is_none_or(|floor| next.row < floor)
+                });
+            match next {
+                Some(next) => {
+                    path.push(next);
+                    sand = next;
+                }
+                None => break,
+            }
+        }
+
+        cave.set(sand, Cell::Sand);
+        units += 1;
+        path.pop();
+    }
+
+    units
+}
+
+#[derive(Debug)]
+pub struct Line(Vec<Point<i64>>);
+
+fn parse_lines(input: &str) -> Vec<Line> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+fn parse_line(input: &str) -> Line {
+    Line(input.split(" -> ").map(parse_point).collect())
+}
+fn parse_point(input: &str) -> Point<i64> {
+    let (_, (col, row)) = pair_parser(",")(input).unwrap();
+    Point::new(row, col)
+}
@@ -0,0 +1,53 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_attributes::aoc_main;
+use itertools::Itertools;
+
+#[aoc_main(year = 2022, day = 3, part1 = "part1", part2 = "part2")]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn part1(input: &str) -> u32 {
+    input
+        .lines()
+        .map(|line| line.split_at(line.len() / 2))
+        .map(|(left, right)| {
+            (
+                left.chars().collect::<HashSet<char>>(),
+                right.chars().collect::<HashSet<char>>(),
+            )
+        })
+        .map(|(left, right)| left.into_iter().filter(move |char| right.contains(char)))
+        .map(|chars| chars.map(point).sum::<u32>())
+        .sum()
+}
+
+pub fn point(c: char) -> u32 {
+    let diff = if c.is_uppercase() { 38 } else { 96 };
+    c as u32 - diff
+}
+
+pub fn part2(input: &str) -> u32 {
+    input
+        .lines()
+        .chunks(3)
+        .into_iter()
+        .map(|chunk| {
+            chunk
+                .fold(HashMap::new(), |mut acc, item| {
+                    item.chars().unique().for_each(|c| {
+                        let entry = acc.entry(c).or_insert(0);
+                        *entry += 1;
+                    });
+                    acc
+                })
+                .iter()
+                .filter_map(|(k, v)| if *v == 3 { Some(point(*k)) } else { None })
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {}
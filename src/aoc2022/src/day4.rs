@@ -0,0 +1,60 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use aoc_attributes::{aoc_main, AocParse};
+use aoc_common::parse::{parse_lines, LineErrors};
+
+#[aoc_main(
+    year = 2022,
+    day = 4,
+    generator = "pairs",
+    part1 = "part1",
+    part2 = "part2"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+#[derive(AocParse)]
+#[aoc(pattern = "{start}-{end}")]
+pub struct ElfSections {
+    start: u32,
+    end: u32,
+}
+
+impl ElfSections {
+    fn range(&self) -> RangeInclusive<u32> {
+        self.start..=self.end
+    }
+
+    pub fn contains(&self, other: &ElfSections) -> bool {
+        self.range().contains(&other.start) && self.range().contains(&other.end)
+    }
+    pub fn overlaps(&self, other: &ElfSections) -> bool {
+        self.range().contains(&other.start) || self.range().contains(&other.end)
+    }
+}
+
+#[derive(AocParse)]
+#[aoc(pattern = "{0},{1}")]
+pub struct ElfPair(ElfSections, ElfSections);
+
+impl ElfPair {
+    pub fn overlap(&self) -> bool {
+        self.0.contains(&self.1) || self.1.contains(&self.0)
+    }
+
+    pub fn partial_overlap(&self) -> bool {
+        self.0.overlaps(&self.1) || self.1.overlaps(&self.0)
+    }
+}
+
+pub fn pairs(input: &str, strict: bool) -> Result<Vec<ElfPair>, LineErrors> {
+    parse_lines(input, ElfPair::from_str, strict)
+}
+
+pub fn part1(pairs: &[ElfPair]) -> usize {
+    pairs.iter().filter(|pair| pair.overlap()).count()
+}
+pub fn part2(pairs: &[ElfPair]) -> usize {
+    pairs.iter().filter(|pair| pair.partial_overlap()).count()
+}
@@ -0,0 +1,158 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use anyhow::Context;
+use aoc_attributes::{aoc_main, AocParse};
+use itertools::Itertools;
+
+#[aoc_main(
+    year = 2022,
+    day = 5,
+    part1 = "part1",
+    part2 = "part2",
+    sample = "sample5.txt",
+    sample_part1 = "CMZ",
+    sample_part2 = "MCD"
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+pub struct Ship(Vec<Stack>);
+
+pub trait Mover {
+    fn apply(ship: &mut Ship, movement: Move);
+
+    fn to_move(ship: &mut Ship, movement: &Move) -> Vec<char> {
+        ship.0
+            .get_mut(movement.from.0)
+            .map(|from| {
+                (0..movement.quantity)
+                    .filter_map(|_| from.0.pop_back())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    }
+}
+
+pub struct CrateMover9000;
+
+impl Mover for CrateMover9000 {
+    fn apply(ship: &mut Ship, movement: Move) {
+        let to_move = Self::to_move(ship, &movement);
+        if let Some(to) = ship.0.get_mut(movement.to.0) {
+            for m in to_move {
+                to.0.push_back(m);
+            }
+        }
+    }
+}
+
+pub struct CrateMover9001;
+
+impl Mover for CrateMover9001 {
+    fn apply(ship: &mut Ship, movement: Move) {
+        let to_move = Self::to_move(ship, &movement);
+        if let Some(to) = ship.0.get_mut(movement.to.0) {
+            for m in to_move.into_iter().rev() {
+                to.0.push_back(m);
+            }
+        }
+    }
+}
+
+impl Ship {
+    pub fn add_stack(&mut self) {
+        self.0.push(Stack::default())
+    }
+
+    pub fn push(&mut self, stack: usize, cargo: char) {
+        self.0.get_mut(stack).unwrap().0.push_back(cargo);
+    }
+
+    pub fn apply<M: Mover>(&mut self, movement: Move) {
+        M::apply(self, movement)
+    }
+
+    pub fn tops(&self) -> Vec<Option<char>> {
+        self.0.iter().map(|first| first.0.back().cloned()).collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Stack(VecDeque<char>);
+
+impl FromStr for Ship {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.lines().rev().fold(Ship::default(), |mut acc, item| {
+            let mut idx = 0;
+            for chunk in &item.chars().chunks(4) {
+                let position = chunk
+                    .into_iter()
+                    .find(|c| c.is_numeric() || c.is_alphabetic());
+                match position {
+                    Some(numeric) if numeric.is_numeric() => {
+                        acc.add_stack();
+                    }
+                    Some(alpha) if alpha.is_alphabetic() => {
+                        acc.push(idx, alpha);
+                        idx += 1;
+                    }
+                    _ => {
+                        idx += 1;
+                    }
+                }
+            }
+            acc
+        }))
+    }
+}
+
+/// Stack numbered from 1 in the moves, kept as an index of [`Ship`].
+#[derive(Default, Debug, Clone, Copy)]
+pub struct StackIndex(usize);
+
+impl FromStr for StackIndex {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = s.parse::<usize>()?;
+        Ok(StackIndex(
+            number
+                .checked_sub(1)
+                .context("Stacks are numbered from 1")?,
+        ))
+    }
+}
+
+#[derive(Default, Debug, AocParse)]
+#[aoc(pattern = "move {quantity} from {from} to {to}")]
+pub struct Move {
+    from: StackIndex,
+    to: StackIndex,
+    quantity: usize,
+}
+
+pub fn part1(input: &str) -> anyhow::Result<String> {
+    solve_part::<CrateMover9000>(input)
+}
+
+fn solve_part<M: Mover>(input: &str) -> anyhow::Result<String> {
+    let (initial, moves) = input
+        .split_once("\n\n")
+        .context("Missing the moves after the stacks")?;
+    let mut ship = Ship::from_str(initial)?;
+    let moves = moves
+        .lines()
+        .map(|line| Move::from_str(line).map_err(|err| err.within(input, line)))
+        .collect::<Result<Vec<_>, _>>()?;
+    for movement in moves {
+        ship.apply::<M>(movement);
+    }
+    Ok(ship.tops().into_iter().flatten().join(""))
+}
+pub fn part2(input: &str) -> anyhow::Result<String> {
+    solve_part::<CrateMover9001>(input)
+}
@@ -0,0 +1,65 @@
+use anyhow::Context;
+use aoc_attributes::aoc_main;
+use aoc_common::{Direction4, Grid, Position};
+
+#[aoc_main(
+    year = 2022,
+    day = 8,
+    part1 = "part1",
+    part2 = "part2",
+    sample = "sample8.txt",
+    sample_part1 = 21,
+    sample_part2 = 8
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn part1(input: &str) -> anyhow::Result<usize> {
+    Ok(visible_trees(&forest(input)?))
+}
+pub fn part2(input: &str) -> anyhow::Result<usize> {
+    Ok(max_scenic_score(&forest(input)?))
+}
+
+fn forest(input: &str) -> anyhow::Result<Grid<usize>> {
+    Grid::parse(input, |pos, c| {
+        let height = c
+            .to_digit(10)
+            .with_context(|| format!("Invalid tree height {} at ({},{})", c, pos.row, pos.col))?;
+        Ok(height as usize)
+    })
+}
+
+fn visible_trees(grid: &Grid<usize>) -> usize {
+    grid.iter()
+        .filter(|(pos, height)| {
+            Direction4::ALL
+                .iter()
+                .any(|dir| grid.ray(*pos, *dir).all(|(_, other)| other < *height))
+        })
+        .count()
+}
+
+fn max_scenic_score(grid: &Grid<usize>) -> usize {
+    grid.iter()
+        .map(|(pos, height)| scenic_score(grid, pos, *height))
+        .max()
+        .unwrap_or_default()
+}
+
+fn scenic_score(grid: &Grid<usize>, pos: Position, height: usize) -> usize {
+    Direction4::ALL
+        .iter()
+        .map(|dir| {
+            let mut score = 0;
+            for (_, other) in grid.ray(pos, *dir) {
+                score += 1;
+                if *other >= height {
+                    break;
+                }
+            }
+            score
+        })
+        .product()
+}
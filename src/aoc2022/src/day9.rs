@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+
+use aoc_attributes::aoc_main;
+use aoc_common::{
+    parse::{lines_of, num_parser},
+    Direction4, Point, SparseGrid,
+};
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{map, value},
+    sequence::separated_pair,
+    IResult,
+};
+
+#[aoc_main(
+    year = 2022,
+    day = 9,
+    part1 = "part1",
+    part2 = "part2",
+    sample = "sample9.txt",
+    sample_part1 = 13
+)]
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Ok(())
+}
+
+pub fn part1(input: &str) -> anyhow::Result<usize> {
+    count_positions(input, 1)
+}
+pub fn part2(input: &str) -> anyhow::Result<usize> {
+    count_positions(input, 9)
+}
+
+pub fn count_positions(input: &str, knots: usize) -> anyhow::Result<usize> {
+    Ok(lines_of(input, movement)?
+        .into_iter()
+        .fold(Bridge::new(knots), |mut acc, item| {
+            acc.next(item);
+            acc
+        })
+        .visited()
+        .len())
+}
+
+pub struct Bridge {
+    head: Position,
+    tail: Vec<Position>,
+    visited: SparseGrid<bool>,
+}
+
+impl Bridge {
+    pub fn new(knots: usize) -> Self {
+        let mut visited = SparseGrid::new(false);
+        visited.set(Position::default(), true);
+        Self {
+            visited,
+            head: Position::default(),
+            tail: vec![Position::default(); knots],
+        }
+    }
+
+    pub fn next(&mut self, movements: Move) {
+        for _ in 0..movements.times {
+            self.head = self
+                .head
+                .step(movements.direction)
+                .expect("the rope stays within i64");
+            self.tail = self
+                .tail
+                .iter()
+                .fold((Vec::new(), self.head), |(mut tail, prev), item| {
+                    let next = follow(item, &prev);
+                    tail.push(next);
+                    (tail, next)
+                })
+                .0;
+
+            if let Some(last) = self.tail.last() {
+                self.visited.set(*last, true);
+            }
+        }
+    }
+    pub fn visited(&self) -> &SparseGrid<bool> {
+        &self.visited
+    }
+}
+pub type Position = Point<i64>;
+
+#[allow(dead_code)]
+struct Snake<'a>(&'a Vec<Position>);
+
+impl<'a> Debug for Snake<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line = self
+            .0
+            .iter()
+            .map(|p| format!("({:>2},{:>2})", p.row, p.col))
+            .join(" <-");
+        write!(f, "{}", line)
+    }
+}
+
+/// Next position of a knot pulled by the previous one.
+fn follow(knot: &Position, other: &Position) -> Position {
+    if knot.chebyshev(other) <= 1 {
+        *knot
+    } else {
+        let diff = *other - *knot;
+        *knot + Point::new(diff.row.signum(), diff.col.signum())
+    }
+}
+
+pub struct Move {
+    times: usize,
+    direction: Direction4,
+}
+
+fn movement(input: &str) -> IResult<&str, Move> {
+    let direction = alt((
+        value(Direction4::Right, tag("R")),
+        value(Direction4::Left, tag("L")),
+        value(Direction4::Up, tag("U")),
+        value(Direction4::Down, tag("D")),
+    ));
+    map(
+        separated_pair(direction, tag(" "), num_parser()),
+        |(direction, times)| Move { times, direction },
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::part2;
+
+    #[test]
+    fn test_part2() {
+        let input = r#"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+"#;
+        assert_eq!(part2(input).unwrap(), 36);
+    }
+}
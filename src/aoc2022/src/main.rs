@@ -1,3 +1,6 @@
-fn main() {
-    println!("Hello, world!");
+use aoc_runner::Registry;
+
+fn main() -> anyhow::Result<()> {
+    Registry::new(2022, env!("CARGO_MANIFEST_DIR"), aoc2022::solutions())?.main();
+    Ok(())
 }